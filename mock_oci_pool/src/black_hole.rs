@@ -0,0 +1,40 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod mockblackhole {
+    struct MockBlackHole {
+        vaults: KeyValueStore<ResourceAddress, Vault>,
+    }
+
+    impl MockBlackHole {
+        pub fn instantiate() -> (Global<MockBlackHole>, ComponentAddress) {
+            let component = Self {
+                vaults: KeyValueStore::new(),
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize();
+
+            let component_address = component.address();
+
+            (component, component_address)
+        }
+
+        /// Takes the tokens out of circulation for good
+        pub fn deposit(&mut self, bucket: Bucket) {
+            let resource_address = bucket.resource_address();
+            if self.vaults.get(&resource_address).is_none() {
+                self.vaults
+                    .insert(resource_address, Vault::new(resource_address));
+            }
+
+            self.vaults.get_mut(&resource_address).unwrap().put(bucket);
+        }
+
+        pub fn get_amount(&self, resource_address: ResourceAddress) -> Decimal {
+            self.vaults
+                .get(&resource_address)
+                .map_or(Decimal::ZERO, |vault| vault.amount())
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod mockcaviarninepool {
+    struct MockCaviarNinePool {
+        vault_a: Vault,
+        vault_b: Vault,
+    }
+
+    impl MockCaviarNinePool {
+        pub fn instantiate(
+            bucket_a: Bucket,
+            bucket_b: Bucket,
+        ) -> (Global<MockCaviarNinePool>, ComponentAddress) {
+            let vault_a = Vault::with_bucket(bucket_a);
+            let vault_b = Vault::with_bucket(bucket_b);
+
+            let component = Self { vault_a, vault_b }
+                .instantiate()
+                .prepare_to_globalize(OwnerRole::None)
+                .globalize();
+
+            let component_address = component.address();
+
+            (component, component_address)
+        }
+
+        /// Returns the remainder of the input and the output
+        pub fn swap(&mut self, tokens: Bucket) -> (Bucket, Bucket) {
+            let output_amount = self.get_amount_out(tokens.resource_address(), tokens.amount());
+            let return_bucket = self.vault_b.take(output_amount);
+            let remainder_bucket = Bucket::new(tokens.resource_address());
+            self.vault_a.put(tokens);
+
+            (remainder_bucket, return_bucket)
+        }
+
+        pub fn get_amount_out(&self, _input_address: ResourceAddress, input_amount: Decimal) -> Decimal {
+            crate::constant_product_output(self.vault_a.amount(), self.vault_b.amount(), input_amount)
+        }
+    }
+}
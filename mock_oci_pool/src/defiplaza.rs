@@ -0,0 +1,52 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod mockdefiplazapool {
+    struct MockDefiPlazaPool {
+        vault_a: Vault,
+        vault_b: Vault,
+    }
+
+    impl MockDefiPlazaPool {
+        pub fn instantiate(
+            bucket_a: Bucket,
+            bucket_b: Bucket,
+        ) -> (Global<MockDefiPlazaPool>, ComponentAddress) {
+            let vault_a = Vault::with_bucket(bucket_a);
+            let vault_b = Vault::with_bucket(bucket_b);
+
+            let component = Self { vault_a, vault_b }
+                .instantiate()
+                .prepare_to_globalize(OwnerRole::None)
+                .globalize();
+
+            let component_address = component.address();
+
+            (component, component_address)
+        }
+
+        /// Returns the output and an optional remainder of the input
+        pub fn swap(&mut self, input_bucket: Bucket, output_address: ResourceAddress) -> (Bucket, Option<Bucket>) {
+            assert!(
+                output_address == self.vault_b.resource_address(),
+                "Invalid output resource!"
+            );
+
+            let output_amount =
+                self.quote(input_bucket.resource_address(), input_bucket.amount(), output_address);
+            let return_bucket = self.vault_b.take(output_amount);
+            self.vault_a.put(input_bucket);
+
+            (return_bucket, None)
+        }
+
+        pub fn quote(
+            &self,
+            _input_address: ResourceAddress,
+            input_amount: Decimal,
+            _output_address: ResourceAddress,
+        ) -> Decimal {
+            crate::constant_product_output(self.vault_a.amount(), self.vault_b.amount(), input_amount)
+        }
+    }
+}
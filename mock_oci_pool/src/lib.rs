@@ -1,5 +1,15 @@
 use scrypto::prelude::*;
 
+pub mod black_hole;
+pub mod caviarnine;
+pub mod defiplaza;
+pub mod ociswap_v2;
+
+/// Calculates the output of a swap on a constant product (x * y = k) pool without fees
+pub fn constant_product_output(input_reserve: Decimal, output_reserve: Decimal, input_amount: Decimal) -> Decimal {
+    output_reserve * input_amount / (input_reserve + input_amount)
+}
+
 #[blueprint]
 mod mockocipool {
     struct MockOciPool {
@@ -26,10 +36,15 @@ mod mockocipool {
         }
 
         pub fn swap(&mut self, bucket_a: Bucket) -> Bucket {
-            let return_bucket = self.vault_b.take(bucket_a.amount());
+            let output_amount = self.get_output_amount(bucket_a.resource_address(), bucket_a.amount());
+            let return_bucket = self.vault_b.take(output_amount);
             self.vault_a.put(bucket_a);
 
             return_bucket
         }
+
+        pub fn get_output_amount(&self, _input_address: ResourceAddress, input_amount: Decimal) -> Decimal {
+            crate::constant_product_output(self.vault_a.amount(), self.vault_b.amount(), input_amount)
+        }
     }
 }
@@ -0,0 +1,42 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod mockociswapv2pool {
+    struct MockOciswapV2Pool {
+        vault_a: Vault,
+        vault_b: Vault,
+    }
+
+    impl MockOciswapV2Pool {
+        pub fn instantiate(
+            bucket_a: Bucket,
+            bucket_b: Bucket,
+        ) -> (Global<MockOciswapV2Pool>, ComponentAddress) {
+            let vault_a = Vault::with_bucket(bucket_a);
+            let vault_b = Vault::with_bucket(bucket_b);
+
+            let component = Self { vault_a, vault_b }
+                .instantiate()
+                .prepare_to_globalize(OwnerRole::None)
+                .globalize();
+
+            let component_address = component.address();
+
+            (component, component_address)
+        }
+
+        /// Returns the output and the remainder of the input
+        pub fn swap(&mut self, input_bucket: Bucket) -> (Bucket, Bucket) {
+            let output_amount = self.preview_swap(input_bucket.resource_address(), input_bucket.amount());
+            let return_bucket = self.vault_b.take(output_amount);
+            let remainder_bucket = Bucket::new(input_bucket.resource_address());
+            self.vault_a.put(input_bucket);
+
+            (return_bucket, remainder_bucket)
+        }
+
+        pub fn preview_swap(&self, _input_address: ResourceAddress, input_amount: Decimal) -> Decimal {
+            crate::constant_product_output(self.vault_a.amount(), self.vault_b.amount(), input_amount)
+        }
+    }
+}
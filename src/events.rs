@@ -17,14 +17,82 @@ pub struct PiersRugyardAuctionSettled {
     pub auction: Auction,
 }
 
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct PiersRugyardAuctionUnsold {
+    pub auction: Auction,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct PiersRugyardAuctionCancelled {
+    pub auction: Auction,
+    pub placement: NftPlacement,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct PiersRugyardPaused {
+    pub timestamp: Instant,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct PiersRugyardUnpaused {
+    pub timestamp: Instant,
+    pub paused_seconds: i64,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct PiersRugyardConfigUpdated {
+    pub update: ConfigUpdate,
+}
+
 #[derive(ScryptoSbor, ScryptoEvent)]
 pub struct PiersRugyardAuctionBid {
     pub auction: Auction,
     pub bid: Bid,
 }
 
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct PiersRugyardAuctionExtended {
+    pub auction_id: u64,
+    pub end_timestamp: Instant,
+    pub extension_count: u64,
+}
+
 #[derive(ScryptoSbor, ScryptoEvent)]
 pub struct PiersRugyardEarlyBuy {
     pub xrd_amount: Decimal,
     pub early_amount: Decimal,
+    pub early_burned: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct PiersRugyardProfitSplit {
+    pub auction_id: u64,
+    pub shares: Vec<(ProfitDestination, Decimal)>,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct PiersRugyardDividendsClaimed {
+    pub nfts: Vec<NonFungibleLocalId>,
+    pub amount: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct PiersRugyardSwapSkipped {
+    pub xrd_amount: Decimal,
+    pub expected_early_amount: Decimal,
+    pub minimum_early_amount: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct PiersRugyardSealedBidCommitted {
+    pub auction: Auction,
+    pub bidder: Global<Account>,
+    pub commitment: Hash,
+    pub deposit: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent)]
+pub struct PiersRugyardSealedBidRevealed {
+    pub auction: Auction,
+    pub bid: Bid,
 }
@@ -1,3 +1,5 @@
+#![allow(clippy::too_many_arguments)]
+
 use events::*;
 use scrypto::prelude::*;
 use types::*;
@@ -6,31 +8,113 @@ pub mod events;
 pub mod types;
 
 #[blueprint]
-#[types(u64, Auction, NFT)]
+#[types(
+    u64,
+    Auction,
+    NFT,
+    NonFungibleLocalId,
+    Decimal,
+    AuctionType,
+    AuctionConfig,
+    FungibleVault,
+    Vec<SealedBid>,
+    BidKey,
+    Bid,
+    ComponentAddress,
+    NftAuctionKey,
+    WinnerAuctionKey,
+    Vec<NonFungibleLocalId>
+)]
 #[events(
     PiersRugyardAuctionBid,
+    PiersRugyardAuctionCancelled,
+    PiersRugyardAuctionExtended,
     PiersRugyardAuctionSettled,
     PiersRugyardAuctionStarted,
+    PiersRugyardAuctionUnsold,
+    PiersRugyardConfigUpdated,
+    PiersRugyardDividendsClaimed,
     PiersRugyardEarlyBuy,
-    PiersRugyardMinted
+    PiersRugyardMinted,
+    PiersRugyardPaused,
+    PiersRugyardProfitSplit,
+    PiersRugyardSealedBidCommitted,
+    PiersRugyardSealedBidRevealed,
+    PiersRugyardSwapSkipped,
+    PiersRugyardUnpaused
 )]
 mod piers_rugyard {
     enable_method_auth! {
+        roles {
+            keeper => updatable_by: [OWNER];
+            pauser => updatable_by: [OWNER];
+        },
         methods {
             settle_auction => PUBLIC;
             start_new_auction => PUBLIC;
             bid => PUBLIC;
+            place_max_bid => PUBLIC;
+            increase_bid => PUBLIC;
+            buy_now => PUBLIC;
+            commit_bid => PUBLIC;
+            reveal_bid => PUBLIC;
+            claim_dividends => PUBLIC;
             mint_nfts => restrict_to: [OWNER];
             withdraw_profits => restrict_to: [OWNER];
-            update_pool_address => restrict_to: [OWNER];
+            update_swap_pool => restrict_to: [OWNER];
+            update_swap_protection => restrict_to: [OWNER];
+            withdraw_treasury_xrd => restrict_to: [OWNER];
+            update_deferred_swaps => restrict_to: [OWNER];
+            update_profit_split => restrict_to: [OWNER];
+            update_early_burn => restrict_to: [OWNER];
+            update_dividend_percentage => restrict_to: [OWNER];
+            withdraw_xrd_profits => restrict_to: [OWNER];
+            execute_swap => restrict_to: [keeper, OWNER];
             update_auction_duration => restrict_to: [OWNER];
             update_auction_buffer => restrict_to: [OWNER];
+            update_extension_limits => restrict_to: [OWNER];
+            update_auction_schedule => restrict_to: [OWNER];
             update_minimum_bid_increase => restrict_to: [OWNER];
+            update_minimum_bid_increase_percentage => restrict_to: [OWNER];
+            update_settler_reward => restrict_to: [OWNER];
+            update_reserve_price => restrict_to: [OWNER];
+            set_nft_reserve_price => restrict_to: [OWNER];
+            update_starting_price => restrict_to: [OWNER];
+            set_nft_starting_price => restrict_to: [OWNER];
+            set_nft_auction_type => restrict_to: [OWNER];
+            set_nft_auction_config => restrict_to: [OWNER];
             delete_nft => restrict_to: [OWNER];
+            create_lot => restrict_to: [OWNER];
+            remove_lot => restrict_to: [OWNER];
             flip_status => restrict_to: [OWNER];
+            pause => restrict_to: [pauser, OWNER];
+            unpause => restrict_to: [pauser, OWNER];
+            cancel_current_auction => restrict_to: [OWNER];
+            update_max_active_auctions => restrict_to: [OWNER];
             get_current_auction => PUBLIC;
+            get_active_auctions => PUBLIC;
+            get_next_start_timestamp => PUBLIC;
+            get_paused_at => PUBLIC;
             get_completed_auction => PUBLIC;
+            get_completed_auctions => PUBLIC;
+            get_auctions_by_nft => PUBLIC;
+            get_auctions_by_winner => PUBLIC;
+            get_auction_count => PUBLIC;
+            get_auction_status => PUBLIC;
+            get_bids => PUBLIC;
             get_profit_amount => PUBLIC;
+            get_settler_reward => PUBLIC;
+            get_swap_pool => PUBLIC;
+            get_swap_protection => PUBLIC;
+            get_treasury_amount => PUBLIC;
+            get_pending_burn_amount => PUBLIC;
+            get_deferred_swaps => PUBLIC;
+            get_profit_split => PUBLIC;
+            get_early_burn => PUBLIC;
+            get_total_early_burned => PUBLIC;
+            get_dividend_percentage => PUBLIC;
+            get_claimable_dividends => PUBLIC;
+            get_xrd_profit_amount => PUBLIC;
             deposit_xrd_domain => restrict_to: [OWNER];
             withdraw_xrd_domain => restrict_to: [OWNER];
         }
@@ -38,21 +122,55 @@ mod piers_rugyard {
 
     struct PiersRugyard {
         early_pool: ComponentAddress,
+        swap_adapter: SwapAdapter,
         early_address: ResourceAddress,
         auction_duration_minutes: u64,
         auction_buffer_minutes: u64,
+        extension_limits: ExtensionLimits,
+        auction_schedule: AuctionSchedule,
+        last_auction_end_timestamp: Option<Instant>,
+        first_start_timestamp: Option<Instant>,
         completed_auctions: KeyValueStore<u64, Auction>,
-        current_auction: Option<Auction>,
+        bids: KeyValueStore<BidKey, Bid>,
+        nft_auction_counts: KeyValueStore<NonFungibleLocalId, u64>,
+        nft_auction_ids: KeyValueStore<NftAuctionKey, u64>,
+        winner_auction_counts: KeyValueStore<ComponentAddress, u64>,
+        winner_auction_ids: KeyValueStore<WinnerAuctionKey, u64>,
+        active_auctions: KeyValueStore<u64, Auction>,
+        active_auction_ids: Vec<u64>,
+        max_active_auctions: u64,
         available_nfts_vault: NonFungibleVault,
-        highest_bid_vault: FungibleVault,
+        bid_vaults: KeyValueStore<u64, FungibleVault>,
         early_vault: FungibleVault,
         total_early_bought: Decimal,
+        early_burn: Option<EarlyBurn>,
+        total_early_burned: Decimal,
+        dividend_percentage: Decimal,
+        dividend_vault: FungibleVault,
+        dividend_per_nft: Decimal,
+        dividend_checkpoints: KeyValueStore<NonFungibleLocalId, Decimal>,
+        swap_protection: Option<SwapProtection>,
+        xrd_treasury_vault: FungibleVault,
+        pending_burn_xrd: Decimal,
+        deferred_swaps: Option<DeferredSwaps>,
+        profit_split: Vec<ProfitShare>,
+        xrd_profits_vault: FungibleVault,
         minimum_bid_increase: Decimal,
+        minimum_bid_increase_percentage: Decimal,
+        settler_reward: SettlerReward,
+        reserve_price: Decimal,
+        starting_price: Decimal,
+        nft_auction_types: KeyValueStore<NonFungibleLocalId, AuctionType>,
+        nft_auction_configs: KeyValueStore<NonFungibleLocalId, AuctionConfig>,
+        sealed_bids: KeyValueStore<u64, Vec<SealedBid>>,
+        proxy_max_bids: KeyValueStore<u64, Decimal>,
         locker: Global<AccountLocker>,
         owner_resource: ResourceAddress,
         active: bool,
+        paused_at: Option<Instant>,
         nft_manager: NonFungibleResourceManager,
         available_nfts_list: Vec<NonFungibleLocalId>,
+        lots: KeyValueStore<NonFungibleLocalId, Vec<NonFungibleLocalId>>,
         next_nft_id: u64,
         next_auction_id: u64,
         xrd_domain_resource: ResourceAddress,
@@ -118,21 +236,68 @@ mod piers_rugyard {
             // Instantiate and globalize
             Self {
                 early_pool,
+                swap_adapter: SwapAdapter::OciswapV1,
                 auction_duration_minutes,
                 auction_buffer_minutes,
+                extension_limits: ExtensionLimits {
+                    max_extensions: None,
+                    max_extension_minutes: None,
+                },
+                auction_schedule: AuctionSchedule {
+                    daily_start_minutes: None,
+                    cooldown_minutes: None,
+                },
+                last_auction_end_timestamp: None,
+                first_start_timestamp: None,
                 completed_auctions: KeyValueStore::<u64, Auction>::new_with_registered_type(),
-                current_auction: None,
+                bids: KeyValueStore::<BidKey, Bid>::new_with_registered_type(),
+                nft_auction_counts: KeyValueStore::<NonFungibleLocalId, u64>::new_with_registered_type(),
+                nft_auction_ids: KeyValueStore::<NftAuctionKey, u64>::new_with_registered_type(),
+                winner_auction_counts: KeyValueStore::<ComponentAddress, u64>::new_with_registered_type(),
+                winner_auction_ids: KeyValueStore::<WinnerAuctionKey, u64>::new_with_registered_type(),
+                active_auctions: KeyValueStore::<u64, Auction>::new_with_registered_type(),
+                active_auction_ids: Vec::new(),
+                max_active_auctions: 1,
                 available_nfts_vault: NonFungibleVault::new(nft_manager.address()),
-                highest_bid_vault: FungibleVault::new(XRD),
+                bid_vaults: KeyValueStore::<u64, FungibleVault>::new_with_registered_type(),
                 early_vault: FungibleVault::new(early_address),
                 total_early_bought: dec!(0),
+                early_burn: None,
+                total_early_burned: dec!(0),
+                dividend_percentage: dec!(0),
+                dividend_vault: FungibleVault::new(early_address),
+                dividend_per_nft: dec!(0),
+                dividend_checkpoints: KeyValueStore::<NonFungibleLocalId, Decimal>::new_with_registered_type(),
+                swap_protection: None,
+                xrd_treasury_vault: FungibleVault::new(XRD),
+                pending_burn_xrd: dec!(0),
+                deferred_swaps: None,
+                profit_split: vec![ProfitShare {
+                    destination: ProfitDestination::SwapToEarly,
+                    percentage: dec!(1),
+                }],
+                xrd_profits_vault: FungibleVault::new(XRD),
                 minimum_bid_increase,
+                minimum_bid_increase_percentage: dec!(0),
+                settler_reward: SettlerReward {
+                    percentage: dec!(0.05),
+                    cap: None,
+                    floor: None,
+                },
+                reserve_price: dec!(0),
+                starting_price: minimum_bid_increase,
+                nft_auction_types: KeyValueStore::<NonFungibleLocalId, AuctionType>::new_with_registered_type(),
+                nft_auction_configs: KeyValueStore::<NonFungibleLocalId, AuctionConfig>::new_with_registered_type(),
+                sealed_bids: KeyValueStore::<u64, Vec<SealedBid>>::new_with_registered_type(),
+                proxy_max_bids: KeyValueStore::<u64, Decimal>::new_with_registered_type(),
                 locker: account_locker,
                 owner_resource,
                 active: false,
+                paused_at: None,
                 early_address,
                 nft_manager,
                 available_nfts_list: Vec::new(),
+                lots: KeyValueStore::<NonFungibleLocalId, Vec<NonFungibleLocalId>>::new_with_registered_type(),
                 next_nft_id: 1,
                 next_auction_id: 1,
                 xrd_domain_resource,
@@ -140,21 +305,31 @@ mod piers_rugyard {
             }
             .instantiate()
             .prepare_to_globalize(OwnerRole::Updatable(owner_rule.clone()))
+            .roles(roles!(
+                keeper => rule!(deny_all);
+                pauser => rule!(deny_all);
+            ))
             .with_address(address_reservation)
             .globalize()
         }
 
-        /// Starts a new auction of one is not already active. We also make sure
-        /// there is actually an NFT to auction and the auction system is active.
+        /// Starts a new auction if there is a free auction slot. We also make sure
+        /// there is actually an NFT to auction, the auction system is active and
+        /// the next scheduled start time has passed.
         ///
         /// # Panics
-        /// * An auction is already active
+        /// * Component is paused
+        /// * All auction slots are in use
         /// * There are no NFTs to auction
+        /// * The next auction is not allowed to start yet
+        /// * The auction config of the NFT is invalid with the current defaults
         pub fn start_new_auction(&mut self) {
-            // Make sure there's not already an auction active
+            self.assert_not_paused();
+
+            // Make sure there's a free auction slot
             assert!(
-                self.current_auction.is_none(),
-                "There's already an auction active!"
+                (self.active_auction_ids.len() as u64) < self.max_active_auctions,
+                "All auction slots are in use!"
             );
 
             // Make sure we have at least 1 NFT to auction
@@ -166,28 +341,111 @@ mod piers_rugyard {
             // Make sure we are allowed to start new auctions at the moment
             assert!(self.active, "Auctions are not active at the moment!");
 
-            // Calculate the start and end timestamps
+            // Make sure the next auction is allowed to start according to the schedule
+            let scheduled_start_timestamp = self.next_start_timestamp();
+            if let Some(scheduled_start_timestamp) = scheduled_start_timestamp {
+                assert!(
+                    Clock::current_time_is_at_or_after(scheduled_start_timestamp, TimePrecisionV2::Second),
+                    "The next auction can't start yet!"
+                );
+            }
+
+            // Get the next NFT local id for the auction. If it's the lead NFT of a lot, the
+            // whole lot is auctioned and the settings of the lead NFT are used.
+            let nft_local_id = self.available_nfts_list.remove(0);
+            let nfts = self
+                .lots
+                .get(&nft_local_id)
+                .map(|lot| lot.clone())
+                .unwrap_or(vec![nft_local_id.clone()]);
+
+            // Use the auction type configured for this NFT, or a regular auction
+            let auction_type = self
+                .nft_auction_types
+                .get(&nft_local_id)
+                .map(|auction_type| auction_type.clone())
+                .unwrap_or(AuctionType::English);
+
+            // Use the auction config overrides for this NFT if there are any
+            let auction_config = self.get_nft_auction_config(&nft_local_id);
+            auction_config.validate(self.auction_duration_minutes, self.auction_buffer_minutes);
+            let duration_minutes = auction_config
+                .duration_minutes
+                .unwrap_or(self.auction_duration_minutes);
+            let buffer_minutes = auction_config
+                .buffer_minutes
+                .unwrap_or(self.auction_buffer_minutes);
+            let minimum_bid_increase = auction_config
+                .minimum_bid_increase
+                .unwrap_or(self.minimum_bid_increase);
+            let starting_price = auction_config.starting_price.unwrap_or(self.starting_price);
+            let buy_now_price = auction_config.buy_now_price;
+
+            // The floor price is the reserve price of a Dutch auction, so a purchase is never
+            // closed as unsold
+            let reserve_price = match &auction_type {
+                AuctionType::Dutch(dutch_auction) => dutch_auction.floor_price,
+                _ => auction_config.reserve_price.unwrap_or(self.reserve_price),
+            };
+
+            // Calculate the start and end timestamps. Sealed bid auctions get a reveal
+            // phase after the commit phase.
             let start_timestamp = Clock::current_time(TimePrecisionV2::Second);
-            let end_timestamp = start_timestamp
-                .add_minutes(self.auction_duration_minutes as i64)
+            let mut end_timestamp = start_timestamp
+                .add_minutes(duration_minutes as i64)
                 .expect("Could not calculate end timestamp");
+            let mut commit_end_timestamp = None;
 
-            // Get the next NFT local id for the auction
-            let nft_local_id = self.available_nfts_list.remove(0);
+            if let AuctionType::SealedBid(sealed_bid_auction) = &auction_type {
+                commit_end_timestamp = Some(end_timestamp);
+                end_timestamp = end_timestamp
+                    .add_minutes(sealed_bid_auction.reveal_duration_minutes as i64)
+                    .expect("Could not calculate end timestamp");
+            }
 
-            // Create the auction struct and assign it as the current auction
+            // Bids can't extend the auction past the hard end
+            let hard_end_timestamp = self
+                .extension_limits
+                .max_extension_minutes
+                .map(|minutes| {
+                    end_timestamp
+                        .add_minutes(minutes as i64)
+                        .expect("Could not calculate hard end timestamp")
+                });
+
+            // Create the auction struct and add it to the active auctions
             let auction = Auction {
                 id: self.next_auction_id,
                 start_timestamp,
                 end_timestamp,
-                nft: nft_local_id.clone(),
+                nfts,
                 highest_bid: None,
                 highest_bidder: None,
                 bid_count: 0,
-                latest_bids: Vec::new()
+                latest_bids: Vec::new(),
+                reserve_price,
+                starting_price,
+                buffer_minutes,
+                minimum_bid_increase,
+                minimum_bid_increase_percentage: self.minimum_bid_increase_percentage,
+                auction_type,
+                commit_end_timestamp,
+                buy_now_price,
+                scheduled_start_timestamp,
+                extension_count: 0,
+                max_extensions: self.extension_limits.max_extensions,
+                hard_end_timestamp,
+                status: AuctionStatus::Live,
+                settled_timestamp: None,
+                settler: None,
+                settler_reward: dec!(0),
+                early_received: dec!(0),
+                final_price: None,
             };
 
-            self.current_auction = Some(auction.clone());
+            self.active_auctions.insert(auction.id, auction.clone());
+            self.active_auction_ids.push(auction.id);
+            self.bid_vaults.insert(auction.id, FungibleVault::new(XRD));
 
             // Emit event
             Runtime::emit_event(PiersRugyardAuctionStarted { auction });
@@ -196,301 +454,1901 @@ mod piers_rugyard {
             self.next_auction_id += 1;
         }
 
-        /// Places a new bid on the currently auctioned NFT. A new bid must be higher than the
-        /// current highest bid with a minimum increase. The bid must also be placed before the
+        /// Places a new bid on an active auction. A new bid must be higher than the
+        /// current highest bid with a minimum increase, and the first bid must meet the
+        /// starting price of the auction. The bid must also be placed before the
         /// auction has ended, except when no previous bid was present, in which case the first
         /// bid will automatically be the winning bid. When the new bid is valid, the previous
         /// highest bid gets returned to the originating account.
         ///
+        /// If the highest bidder placed a proxy bid with a maximum that is at least as high as
+        /// the new bid, the proxy bid outbids the new bid right away and the new bid is returned.
+        ///
         /// If a bid happens shortly before the auction ends, the auction gets extended. This
         /// keeps happening until the auction ends without new bids shortly before it.
         ///
         /// # Input
+        /// * `auction_id`: a u64 with the id of the auction to bid on
         /// * `bid`: a Bucket containing the resource the bid is done with
         /// * `account`: a Global<Account> so we can register which account made the bid
         ///
+        /// # Output
+        /// * An Option<FungibleBucket> with the settlement reward if the auction was settled,
+        ///   or the returned bid if it was outbid by a proxy bid
+        /// * An Option<NonFungibleBucket> with the NFT if the auction was settled
+        ///
         /// # Panics
-        /// * No auction is active
+        /// * Component is paused
+        /// * Auction is not active
         /// * Bid resource is invalid
         /// * Bid increase is too low
+        /// * First bid is lower than the starting price
+        /// * Bid happens after auction ended while there was already a bid
+        /// * The auction is not an English auction
+        pub fn bid(&mut self, auction_id: u64, bid: Bucket, account: Global<Account>) -> (Option<FungibleBucket>, Option<NonFungibleBucket>) {
+            self.place_bid(auction_id, bid, account, false)
+        }
+
+        /// Places a proxy bid on an active auction. The bucket is escrowed as the
+        /// maximum bid, and the component automatically outbids other bidders by the minimum
+        /// bid increase until that maximum is reached, and at least up to the reserve price if the
+        /// maximum allows it. Only the current price is shown as the highest bid. The unused part of the maximum is refunded when the auction is settled,
+        /// and the whole maximum is refunded when the proxy bid is outbid.
+        ///
+        /// # Input
+        /// * `auction_id`: a u64 with the id of the auction to bid on
+        /// * `max_bid`: a Bucket containing the maximum amount to bid
+        /// * `account`: a Global<Account> so we can register which account made the bid
+        ///
+        /// # Output
+        /// * An Option<FungibleBucket> with the settlement reward if the auction was settled,
+        ///   or the returned bid if it was outbid by another proxy bid
+        /// * An Option<NonFungibleBucket> with the NFT if the auction was settled
+        ///
+        /// # Panics
+        /// * Auction is not active
+        /// * Bid resource is invalid
+        /// * Maximum bid is lower than the highest bid plus the minimum increase
+        /// * Maximum bid is lower than the starting price
         /// * Bid happens after auction ended while there was already a bid
-        pub fn bid(&mut self, bid: Bucket, account: Global<Account>) -> (Option<FungibleBucket>, Option<NonFungibleBucket>) {
+        /// * The auction is not an English auction
+        pub fn place_max_bid(&mut self, auction_id: u64, max_bid: Bucket, account: Global<Account>) -> (Option<FungibleBucket>, Option<NonFungibleBucket>) {
+            self.place_bid(auction_id, max_bid, account, true)
+        }
+
+        /// Raises the bid of the current highest bidder by the amount sent, instead of placing
+        /// a whole new bid. If the highest bid is a proxy bid, its maximum is raised as well.
+        /// The increase must be at least the minimum bid increase, and the auction gets extended
+        /// like it does for a new bid.
+        ///
+        /// # Input
+        /// * `auction_id`: a u64 with the id of the auction
+        /// * `increase`: a Bucket containing the XRD to add to the bid
+        /// * `account`: a Global<Account> of the highest bidder
+        ///
+        /// # Panics
+        /// * Component is paused
+        /// * Auction is not active
+        /// * The auction is not an English auction
+        /// * The account is not the highest bidder
+        /// * Auction has ended
+        /// * Increase resource is invalid
+        /// * Increase is lower than the minimum bid increase
+        pub fn increase_bid(&mut self, auction_id: u64, increase: Bucket, account: Global<Account>) {
+            self.assert_not_paused();
+
             // Ensure the caller owns the account
             Runtime::assert_access_rule(account.get_owner_role().rule);
 
             let current_timestamp = Clock::current_time(TimePrecisionV2::Second);
-            let auction: &mut Auction = self.current_auction.as_mut().expect("No auction active!");
-            let highest_bid_amount = auction.highest_bid.unwrap_or(dec!(0));
+            let mut auction = self.get_active_auction(auction_id);
+
+            // Assert the increase is valid
+            assert!(
+                auction.auction_type == AuctionType::English,
+                "Bidding is not available for this auction!"
+            );
+            assert!(
+                auction.highest_bidder == Some(account),
+                "You are not the highest bidder!"
+            );
+            assert!(
+                current_timestamp < auction.end_timestamp,
+                "Auction has ended!"
+            );
+            assert!(increase.resource_address() == XRD, "You must bid with XRD!");
+            assert!(
+                increase.amount() >= auction.minimum_increase_over(auction.highest_bid.unwrap()),
+                "Bid increase not high enough!"
+            );
+
+            // Raise the bid and the proxy maximum, if any
+            let new_amount = auction.highest_bid.unwrap() + increase.amount();
+            auction.highest_bid = Some(new_amount);
+            if let Some(mut proxy_max_bid) = self.proxy_max_bids.get_mut(&auction_id) {
+                *proxy_max_bid += increase.amount();
+            }
+            self.bid_vaults
+                .get_mut(&auction_id)
+                .unwrap()
+                .put(increase.as_fungible());
+
+            // Extend the auction if necessary
+            if auction.extend(current_timestamp, auction.buffer_minutes) {
+                Runtime::emit_event(PiersRugyardAuctionExtended {
+                    auction_id,
+                    end_timestamp: auction.end_timestamp,
+                    extension_count: auction.extension_count,
+                });
+            }
 
             // Add the bid to the bid history
             let new_bid = Bid {
-                amount: bid.amount(),
+                amount: new_amount,
                 bidder: account,
                 timestamp: current_timestamp,
                 transaction_hash: Runtime::transaction_hash(),
+                is_proxy: false,
             };
+            self.add_bid(&mut auction, new_bid.clone());
 
-            // Add this bid to the bid history. Keep the bid history to 10 pieces as to not brick the component.
-            auction.bid_count += 1;
-            if auction.latest_bids.len() == 10 {
-                auction.latest_bids.remove(0);
-            }
-            auction.latest_bids.push(new_bid.clone());
-
-            // Emit event here so we can clone `auction`
             Runtime::emit_event(PiersRugyardAuctionBid {
                 auction: auction.clone(),
                 bid: new_bid,
             });
 
-            // Assert the bid is valid
-            assert!(bid.resource_address() == XRD, "You must bid with XRD!");
+            self.active_auctions.insert(auction_id, auction);
+        }
+
+        /// Places a regular or a proxy bid. For a proxy bid, the bucket contains the maximum
+        /// bid instead of the bid itself.
+        fn place_bid(&mut self, auction_id: u64, bid: Bucket, account: Global<Account>, is_proxy: bool) -> (Option<FungibleBucket>, Option<NonFungibleBucket>) {
+            self.assert_not_paused();
+
+            // Ensure the caller owns the account
+            Runtime::assert_access_rule(account.get_owner_role().rule);
+
+            let current_timestamp = Clock::current_time(TimePrecisionV2::Second);
+            let mut auction = self.get_active_auction(auction_id);
+
             assert!(
-                bid.amount() - highest_bid_amount >= self.minimum_bid_increase,
-                "Bid increase not high enough!"
+                auction.auction_type == AuctionType::English,
+                "Bidding is not available for this auction!"
             );
 
+            // Assert the bid is valid
+            let amount = bid.amount();
+            assert!(bid.resource_address() == XRD, "You must bid with XRD!");
+            match auction.highest_bid {
+                Some(highest_bid_amount) => assert!(
+                    amount - highest_bid_amount >= auction.minimum_increase_over(highest_bid_amount),
+                    "Bid increase not high enough!"
+                ),
+                None => assert!(
+                    amount >= auction.starting_price,
+                    "Bid is lower than the starting price!"
+                ),
+            }
+
             // If we already have a bid, ensure we can still accept new bids
-            // and return the previous bid.
-            let mut first_bidder = false;
-            if let Some(highest_bidder) = auction.highest_bidder {
+            let first_bidder = auction.highest_bidder.is_none();
+            if !first_bidder {
                 assert!(
                     current_timestamp < auction.end_timestamp,
                     "Auction has ended!"
                 );
+            }
 
-                let old_bid = self.highest_bid_vault.take_all();
-                self.locker.store(highest_bidder, old_bid.into(), true);
-            } else {
-                first_bidder = true;
+            // The highest bidder is prepared to go up to their proxy maximum, or just their bid
+            let leader_max_bid = self
+                .proxy_max_bids
+                .get(&auction_id)
+                .map(|proxy_max_bid| *proxy_max_bid)
+                .or(auction.highest_bid);
+            let mut new_bids: Vec<Bid> = Vec::new();
+            let mut returned_bid: Option<FungibleBucket> = None;
+
+            match (auction.highest_bidder, leader_max_bid) {
+                (Some(highest_bidder), Some(leader_max_bid)) if leader_max_bid >= amount => {
+                    // The proxy bid of the highest bidder outbids the new bid, so return it
+                    let price = leader_max_bid.min(
+                        (amount + auction.minimum_increase_over(amount)).max(auction.reserve_price),
+                    );
+
+                    new_bids.push(Bid {
+                        amount,
+                        bidder: account,
+                        timestamp: current_timestamp,
+                        transaction_hash: Runtime::transaction_hash(),
+                        is_proxy,
+                    });
+                    new_bids.push(Bid {
+                        amount: price,
+                        bidder: highest_bidder,
+                        timestamp: current_timestamp,
+                        transaction_hash: Runtime::transaction_hash(),
+                        is_proxy: true,
+                    });
+
+                    auction.highest_bid = Some(price);
+                    returned_bid = Some(bid.as_fungible());
+                }
+                (highest_bidder, leader_max_bid) => {
+                    // A proxy bid only goes as high as needed to outbid the previous highest bidder,
+                    // but at least up to the reserve price if its maximum allows it
+                    let price = match (is_proxy, leader_max_bid) {
+                        (true, Some(leader_max_bid)) => amount.min(
+                            (leader_max_bid + auction.minimum_increase_over(leader_max_bid))
+                                .max(auction.reserve_price),
+                        ),
+                        (true, None) => amount.min(auction.starting_price.max(auction.reserve_price)),
+                        (false, _) => amount,
+                    };
+
+                    // Return the previous bid
+                    if let Some(highest_bidder) = highest_bidder {
+                        let old_bid = self.bid_vaults.get_mut(&auction_id).unwrap().take_all();
+                        self.locker.store(highest_bidder, old_bid.into(), true);
+                    }
+
+                    new_bids.push(Bid {
+                        amount: price,
+                        bidder: account,
+                        timestamp: current_timestamp,
+                        transaction_hash: Runtime::transaction_hash(),
+                        is_proxy,
+                    });
+
+                    // Accept the new bid
+                    auction.highest_bid = Some(price);
+                    auction.highest_bidder = Some(account);
+                    if is_proxy {
+                        self.proxy_max_bids.insert(auction_id, amount);
+                    } else {
+                        self.proxy_max_bids.remove(&auction_id);
+                    }
+                    self.bid_vaults
+                        .get_mut(&auction_id)
+                        .unwrap()
+                        .put(bid.as_fungible());
+                }
             }
 
             // Extend the auction if necessary
-            let current_timestamp_plus_buffer = current_timestamp
-                .add_minutes(self.auction_buffer_minutes as i64)
-                .expect("Could not add minutes!");
+            if auction.extend(current_timestamp, auction.buffer_minutes) {
+                Runtime::emit_event(PiersRugyardAuctionExtended {
+                    auction_id,
+                    end_timestamp: auction.end_timestamp,
+                    extension_count: auction.extension_count,
+                });
+            }
 
-            if current_timestamp_plus_buffer >= auction.end_timestamp
-                && current_timestamp < auction.end_timestamp
-            {
-                auction.end_timestamp = current_timestamp_plus_buffer;
+            // Add the bids to the bid history
+            for new_bid in new_bids {
+                self.add_bid(&mut auction, new_bid.clone());
+
+                Runtime::emit_event(PiersRugyardAuctionBid {
+                    auction: auction.clone(),
+                    bid: new_bid,
+                });
             }
 
-            // Accept the new bid
-            auction.highest_bid = Some(bid.amount());
-            auction.highest_bidder = Some(account);
-            self.highest_bid_vault.put(bid.as_fungible());
+            let ended = current_timestamp >= auction.end_timestamp;
+            self.active_auctions.insert(auction_id, auction);
 
             // If this was the first bid AND the auction has ended, we might as well settle it immediately
-            if first_bidder && current_timestamp >= auction.end_timestamp {
+            if first_bidder && ended {
                 info!("Settling auction");
-                let (reward, nft) = self.settle_auction(account);
+                let (reward, nft) = self.settle_auction(auction_id, account);
 
                 (Some(reward), nft)
             } else {
-                (None, None)
+                (returned_bid, None)
             }
         }
 
-        /// Settles an auction if there is one that has ended. Whoever calls
-        /// this method will get a 5% reward for settling the auction.
+        /// Buys the NFT in an active auction right away. The auction is settled in the same
+        /// transaction and any payment above the price is returned together with the settlement
+        /// reward.
         ///
-        /// Profits from the auction are then swapped to EARLY. If it's possible
-        /// to start a new auction, this will be done immediately.
+        /// For Dutch auctions the price drops from the start price to the floor price over the
+        /// duration of the auction, and stays at the floor price once the auction has ended. The
+        /// first account to buy wins the auction.
+        ///
+        /// For regular auctions with a buy now price, the NFT can be bought at that price as long
+        /// as the highest bid, and the maximum of a proxy bid, is below it. The current highest
+        /// bidder gets their bid refunded.
         ///
         /// # Input
-        /// * `account`: A Global<Account> so we know where to send the reward to
+        /// * `auction_id`: a u64 with the id of the auction
+        /// * `payment`: a Bucket containing the XRD to pay with
+        /// * `account`: a Global<Account> so we can register which account bought the NFT
+        ///
+        /// # Output
+        /// * A FungibleBucket with the change and the settlement reward
+        /// * An Option<NonFungibleBucket> with the NFT
         ///
         /// # Panics
-        /// * Auction has not ended yet
-        /// * There is no bid yet
-        pub fn settle_auction(&mut self, account: Global<Account>) -> (FungibleBucket, Option<NonFungibleBucket>) {
+        /// * Component is paused
+        /// * Auction is not active
+        /// * The auction has no buy now option
+        /// * The highest bid is not below the buy now price
+        /// * The maximum of the highest proxy bid is not below the buy now price
+        /// * The buy now price is below the reserve price
+        /// * Auction has ended while there was already a bid
+        /// * Payment resource is invalid
+        /// * Payment is lower than the current price
+        pub fn buy_now(&mut self, auction_id: u64, payment: Bucket, account: Global<Account>) -> (FungibleBucket, Option<NonFungibleBucket>) {
+            self.assert_not_paused();
+
             // Ensure the caller owns the account
             Runtime::assert_access_rule(account.get_owner_role().rule);
 
-            let auction = self.current_auction.as_mut().expect("No auction active!");
             let current_timestamp = Clock::current_time(TimePrecisionV2::Second);
+            let mut auction = self.get_active_auction(auction_id);
+
+            let price = match &auction.auction_type {
+                AuctionType::Dutch(dutch_auction) => dutch_auction.price_at(
+                    auction.start_timestamp,
+                    auction.end_timestamp,
+                    current_timestamp,
+                ),
+                AuctionType::English => {
+                    let buy_now_price = auction
+                        .buy_now_price
+                        .expect("Buy now is not available for this auction!");
+
+                    assert!(
+                        auction
+                            .highest_bid
+                            .map_or(true, |highest_bid| highest_bid < buy_now_price),
+                        "Highest bid is not below the buy now price!"
+                    );
+                    assert!(
+                        self.proxy_max_bids
+                            .get(&auction_id)
+                            .map_or(true, |proxy_max_bid| *proxy_max_bid < buy_now_price),
+                        "Proxy bid of the highest bidder is not below the buy now price!"
+                    );
+                    assert!(
+                        buy_now_price >= auction.reserve_price,
+                        "Buy now price is below the reserve price!"
+                    );
+                    assert!(
+                        current_timestamp < auction.end_timestamp
+                            || auction.highest_bidder.is_none(),
+                        "Auction has ended!"
+                    );
+
+                    buy_now_price
+                }
+                _ => panic!("Buy now is not available for this auction!"),
+            };
 
-            // Emit event
-            Runtime::emit_event(PiersRugyardAuctionSettled {
-                auction: auction.clone(),
-            });
-
-            // Make sure auction time has passed
-            assert!(
-                current_timestamp >= auction.end_timestamp,
-                "Current auction has not ended yet"
-            );
-
-            // Make sure we have a bidder
+            // Assert the payment is valid
+            assert!(payment.resource_address() == XRD, "You must pay with XRD!");
             assert!(
-                auction.highest_bid.is_some(),
-                "No bids were made. Wait until at least 1 bid was made."
+                payment.amount() >= price,
+                "Payment is lower than the current price!"
             );
 
-            // Deposit NFT to the winner. If the current caller is the winner, give it to them directly
-            let nft = self.available_nfts_vault.take_non_fungible(&auction.nft);
-            let mut nft_bucket: Option<NonFungibleBucket> = None;
-            if auction.highest_bidder.unwrap() == account {
-                nft_bucket = Some(nft);
-            } else {
-                self.locker
-                .store(auction.highest_bidder.unwrap(), nft.into(), true);
-            }
-
-            // Take the reward for the account calling this method
-            let mut highest_bid_bucket = self.highest_bid_vault.take_all();
-            let reward = highest_bid_bucket
-                .amount()
-                .checked_mul(dec!(0.05))
-                .expect("Couldn't calculate reward!");
-            let reward_bucket = highest_bid_bucket
-                .take_advanced(reward, WithdrawStrategy::Rounded(RoundingMode::ToZero));
-
-            // Swap for EARLY and deposit
-            let highest_bid_amount = highest_bid_bucket.amount();
+            let mut payment = payment.as_fungible();
+            let price_bucket = payment.take(price);
 
-            let pool_component: Global<AnyComponent> = Global::from(self.early_pool);
-            let early_bucket =
-                pool_component.call_raw::<Bucket>("swap", scrypto_args!(highest_bid_bucket));
+            // Add the purchase to the bid history
+            let new_bid = Bid {
+                amount: price,
+                bidder: account,
+                timestamp: current_timestamp,
+                transaction_hash: Runtime::transaction_hash(),
+                is_proxy: false,
+            };
+            self.add_bid(&mut auction, new_bid.clone());
 
-            Runtime::emit_event(PiersRugyardEarlyBuy {
-                xrd_amount: highest_bid_amount,
-                early_amount: early_bucket.amount(),
+            Runtime::emit_event(PiersRugyardAuctionBid {
+                auction: auction.clone(),
+                bid: new_bid,
             });
 
-            self.total_early_bought += early_bucket.amount();
-            self.early_vault.put(early_bucket.as_fungible());
-
-            // Settle the auction
-            self.completed_auctions.insert(auction.id, auction.clone());
-            self.current_auction = None;
-
-            // Start new auction if possible
-            if !self.available_nfts_list.is_empty() && self.active {
-                self.start_new_auction();
+            // Refund the current highest bidder, if any
+            if let Some(highest_bidder) = auction.highest_bidder {
+                let old_bid = self.bid_vaults.get_mut(&auction_id).unwrap().take_all();
+                self.locker.store(highest_bidder, old_bid.into(), true);
+                self.proxy_max_bids.remove(&auction_id);
             }
 
-            (reward_bucket, nft_bucket)
-        }
-
-        //------ Admin stuff ------//
+            // Accept the purchase and end the auction right away
+            auction.highest_bid = Some(price);
+            auction.highest_bidder = Some(account);
+            if current_timestamp < auction.end_timestamp {
+                auction.end_timestamp = current_timestamp;
+            }
+            self.bid_vaults.get_mut(&auction_id).unwrap().put(price_bucket);
+            self.active_auctions.insert(auction_id, auction);
 
-        /// Withdraws the profits from the EARLY vault
-        pub fn withdraw_profits(&mut self) -> FungibleBucket {
-            self.early_vault.take_all()
-        }
+            let (mut reward, nft) = self.settle_auction(auction_id, account);
+            reward.put(payment);
 
-        /// Updates the pool address used for swapping
-        ///
-        /// # Input
-        /// * `address`: a ComponentAddress of the new pool
-        pub fn update_pool_address(&mut self, address: ComponentAddress) {
-            self.early_pool = address;
+            (reward, nft)
         }
 
-        /// Updates the auction duration
+        /// Commits a sealed bid on an active sealed bid auction. The commitment is the hash
+        /// of the bid amount and a secret salt (see `SealedBid::commitment`), so nobody can see
+        /// the bid until it's revealed. The deposit must be at least the bid amount, but can be
+        /// higher to hide the actual bid. Each account can commit one bid per auction.
         ///
         /// # Input
-        /// * `minutes`: a u64 for the new auction duration in minutes
+        /// * `auction_id`: a u64 with the id of the auction
+        /// * `commitment`: a Hash of the bid amount and salt
+        /// * `deposit`: a Bucket containing the XRD deposit
+        /// * `account`: a Global<Account> so we can register which account made the bid
         ///
         /// # Panics
-        /// * Duration is 0 or lower
-        /// * Duration is shorter or equal to time buffer
-        pub fn update_auction_duration(&mut self, minutes: u64) {
-            assert!(minutes > 0, "Auction duration must be more than 0 minutes!");
+        /// * Component is paused
+        /// * Auction is not active
+        /// * The auction is not a sealed bid auction
+        /// * The commit phase has ended
+        /// * Deposit resource is invalid
+        /// * Deposit is lower than the starting price
+        /// * The account already committed a bid
+        /// * The maximum number of sealed bids has been reached
+        pub fn commit_bid(&mut self, auction_id: u64, commitment: Hash, deposit: Bucket, account: Global<Account>) {
+            self.assert_not_paused();
+
+            // Ensure the caller owns the account
+            Runtime::assert_access_rule(account.get_owner_role().rule);
+
+            let current_timestamp = Clock::current_time(TimePrecisionV2::Second);
+            let auction = self.get_active_auction(auction_id);
+            let mut sealed_bids = self
+                .sealed_bids
+                .get(&auction_id)
+                .map(|sealed_bids| sealed_bids.clone())
+                .unwrap_or_default();
+
+            let commit_end_timestamp = match &auction.auction_type {
+                AuctionType::SealedBid(_) => auction.commit_end_timestamp.unwrap(),
+                _ => panic!("Sealed bids are not available for this auction!"),
+            };
+
+            // Assert the commitment is valid
             assert!(
-                minutes > self.auction_buffer_minutes,
-                "Auction duration must be longer than the auction buffer!"
+                current_timestamp < commit_end_timestamp,
+                "Commit phase has ended!"
+            );
+            assert!(deposit.resource_address() == XRD, "You must deposit XRD!");
+            assert!(
+                deposit.amount() >= auction.starting_price,
+                "Deposit is lower than the starting price!"
+            );
+            assert!(
+                !sealed_bids.iter().any(|sealed_bid| sealed_bid.bidder == account),
+                "You already committed a bid!"
             );
 
-            self.auction_duration_minutes = minutes;
+            // Keep the number of sealed bids limited as to not brick the component
+            assert!(
+                sealed_bids.len() < 50,
+                "Maximum number of sealed bids reached!"
+            );
+
+            Runtime::emit_event(PiersRugyardSealedBidCommitted {
+                auction,
+                bidder: account,
+                commitment,
+                deposit: deposit.amount(),
+            });
+
+            sealed_bids.push(SealedBid {
+                bidder: account,
+                commitment,
+                deposit: deposit.amount(),
+                revealed_amount: None,
+            });
+            self.sealed_bids.insert(auction_id, sealed_bids);
+            self.bid_vaults
+                .get_mut(&auction_id)
+                .unwrap()
+                .put(deposit.as_fungible());
         }
 
-        /// Updates the auction buffer
+        /// Reveals a previously committed sealed bid. The amount and salt must match the
+        /// commitment and the amount can't be higher than the deposit. The highest revealed
+        /// bid wins the auction once the reveal phase has ended.
         ///
         /// # Input
-        /// * `minutes`: a u64 for the new time buffer in minutes
+        /// * `auction_id`: a u64 with the id of the auction
+        /// * `amount`: a Decimal with the bid amount
+        /// * `salt`: a String with the salt used for the commitment
+        /// * `account`: a Global<Account> of the account that committed the bid
         ///
         /// # Panics
-        /// * The buffer is 0 or lower
-        /// * The buffer is higher than the auction duration
-        pub fn update_auction_buffer(&mut self, minutes: u64) {
-            assert!(minutes > 0, "Buffer must be more than 0 minutes!");
+        /// * Component is paused
+        /// * Auction is not active
+        /// * The auction is not a sealed bid auction
+        /// * It's not the reveal phase
+        /// * The account did not commit a bid or already revealed it
+        /// * The amount and salt don't match the commitment
+        /// * The amount is higher than the deposit or lower than the starting price
+        pub fn reveal_bid(&mut self, auction_id: u64, amount: Decimal, salt: String, account: Global<Account>) {
+            self.assert_not_paused();
+
+            // Ensure the caller owns the account
+            Runtime::assert_access_rule(account.get_owner_role().rule);
+
+            let current_timestamp = Clock::current_time(TimePrecisionV2::Second);
+            let mut auction = self.get_active_auction(auction_id);
+            let mut sealed_bids = self
+                .sealed_bids
+                .get(&auction_id)
+                .map(|sealed_bids| sealed_bids.clone())
+                .unwrap_or_default();
+
+            let commit_end_timestamp = match &auction.auction_type {
+                AuctionType::SealedBid(_) => auction.commit_end_timestamp.unwrap(),
+                _ => panic!("Sealed bids are not available for this auction!"),
+            };
+
             assert!(
-                minutes < self.auction_duration_minutes,
-                "Buffer must be lower than the auction duration!"
+                current_timestamp >= commit_end_timestamp
+                    && current_timestamp < auction.end_timestamp,
+                "Not in the reveal phase!"
             );
 
-            self.auction_buffer_minutes = minutes;
-        }
+            let sealed_bid = sealed_bids
+                .iter_mut()
+                .find(|sealed_bid| sealed_bid.bidder == account)
+                .expect("No sealed bid found for this account!");
 
-        /// Upates the minimum bid increase
-        ///
-        /// # Input
-        /// * `minimum_bid_increase`: A Decimal for the new minimum bid increase
-        ///
-        /// # Panics
-        /// * The minimum bid increase is 0 or lower
-        pub fn update_minimum_bid_increase(&mut self, minimum_bid_increase: Decimal) {
+            // Assert the revealed bid is valid
             assert!(
-                minimum_bid_increase > dec!(0),
-                "Minimum bid increase must be higher than 0!"
+                sealed_bid.revealed_amount.is_none(),
+                "Bid was already revealed!"
+            );
+            assert!(
+                SealedBid::commitment(amount, &salt) == sealed_bid.commitment,
+                "Amount and salt don't match the commitment!"
+            );
+            assert!(
+                amount <= sealed_bid.deposit,
+                "Amount is higher than the deposit!"
+            );
+            assert!(
+                amount >= auction.starting_price,
+                "Amount is lower than the starting price!"
             );
 
-            self.minimum_bid_increase = minimum_bid_increase;
-        }
+            sealed_bid.revealed_amount = Some(amount);
+            self.sealed_bids.insert(auction_id, sealed_bids);
 
-        /// Activates or deactives the auction system
-        pub fn flip_status(&mut self) {
-            self.active = !self.active;
+            // Add the bid to the bid history
+            let new_bid = Bid {
+                amount,
+                bidder: account,
+                timestamp: current_timestamp,
+                transaction_hash: Runtime::transaction_hash(),
+                is_proxy: false,
+            };
+            self.add_bid(&mut auction, new_bid.clone());
+
+            // The first highest revealed bid leads the auction
+            if auction.highest_bid.map_or(true, |highest_bid| amount > highest_bid) {
+                auction.highest_bid = Some(amount);
+                auction.highest_bidder = Some(account);
+            }
+
+            Runtime::emit_event(PiersRugyardSealedBidRevealed {
+                auction: auction.clone(),
+                bid: new_bid,
+            });
+
+            self.active_auctions.insert(auction_id, auction);
         }
 
-        /// Mints a new NFT for the collection and puts it in the list
-        /// of NFTs to be auctioned.
+        /// Claims the EARLY dividends of Piers Rugyard NFTs. Each NFT gets its share of the
+        /// EARLY bought since it was sold or since its last claim.
         ///
         /// # Input
-        /// * `nft_data`: an NFT struct with the data for the new NFT        
-        pub fn mint_nfts(&mut self, nft_data: Vec<NFT>) {
-            for data in nft_data {
-                let local_id = NonFungibleLocalId::integer(self.next_nft_id);
-                let nft = self.nft_manager.mint_non_fungible(&local_id, data.clone());
+        /// * `proof`: a NonFungibleProof of the Piers Rugyard NFTs to claim for
+        ///
+        /// # Output
+        /// * A FungibleBucket with the EARLY dividends
+        ///
+        /// # Panics
+        /// * The proof is not of Piers Rugyard NFTs
+        pub fn claim_dividends(&mut self, proof: NonFungibleProof) -> FungibleBucket {
+            let checked_proof = proof.check(self.nft_manager.address());
+            let ids: Vec<NonFungibleLocalId> =
+                checked_proof.non_fungible_local_ids().into_iter().collect();
+
+            let amount = self.claimable_dividends(&ids);
+            for id in &ids {
+                self.dividend_checkpoints.insert(id.clone(), self.dividend_per_nft);
+            }
 
-                // Put NFT in vault and in available NFTs list
-                self.available_nfts_list.push(local_id.clone());
-                self.available_nfts_vault.put(nft);
+            Runtime::emit_event(PiersRugyardDividendsClaimed { nfts: ids, amount });
 
-                Runtime::emit_event(PiersRugyardMinted {
-                    id: local_id,
-                    nft_data: data,
-                });
+            self.dividend_vault
+                .take_advanced(amount, WithdrawStrategy::Rounded(RoundingMode::ToZero))
+        }
 
-                // Increment the NFT id
-                self.next_nft_id += 1;
-            }
+        /// Calculates the unclaimed dividends of a set of NFTs
+        fn claimable_dividends(&self, ids: &[NonFungibleLocalId]) -> Decimal {
+            ids.iter().fold(dec!(0), |total, id| {
+                let checkpoint = self
+                    .dividend_checkpoints
+                    .get(id)
+                    .map_or(dec!(0), |checkpoint| *checkpoint);
+                total + self.dividend_per_nft - checkpoint
+            })
         }
 
-        /// Removes an NFT from the available NFTs list and burns it. This method is protected.
+        /// Settles an auction that has ended. Whoever calls
+        /// this method will get a reward for settling the auction (5% by default).
+        ///
+        /// Profits from the auction are then swapped to EARLY. If the highest bid
+        /// did not meet the reserve price, the auction is closed as unsold instead:
+        /// the bid is refunded, the NFT goes back into the queue and no reward is paid.
+        /// If it's possible to start a new auction, this will be done immediately.
+        ///
+        /// For sealed bid auctions, all deposits except the winner's are returned and the
+        /// winner pays either their own bid or the second highest bid. A sealed bid auction
+        /// without revealed bids is closed as unsold. If the winner placed a proxy bid, the
+        /// unused part of their maximum bid is refunded.
         ///
         /// # Input
-        /// * `id`: A NonFungibleLocalId for the NFT to be deleted
+        /// * `auction_id`: a u64 with the id of the auction to settle
+        /// * `account`: A Global<Account> so we know where to send the reward to
         ///
         /// # Panics
-        /// * NFT does not exist
-        /// * NFT is currently under auction
-        pub fn delete_nft(&mut self, id: NonFungibleLocalId) {
-            assert!(
-                self.available_nfts_list.contains(&id),
-                "NFT is not available!"
-            );
+        /// * Component is paused
+        /// * Auction is not active
+        /// * Auction has not ended yet
+        /// * There is no bid yet
+        pub fn settle_auction(&mut self, auction_id: u64, account: Global<Account>) -> (FungibleBucket, Option<NonFungibleBucket>) {
+            self.assert_not_paused();
 
-            if let Some(auction) = &self.current_auction {
-                assert!(
-                    auction.nft != id,
+            // Ensure the caller owns the account
+            Runtime::assert_access_rule(account.get_owner_role().rule);
+
+            let mut auction = self.get_active_auction(auction_id);
+            let current_timestamp = Clock::current_time(TimePrecisionV2::Second);
+
+            // Make sure auction time has passed
+            assert!(
+                current_timestamp >= auction.end_timestamp,
+                "Current auction has not ended yet"
+            );
+
+            // Make sure we have a bidder. Sealed bid auctions can't get any bids after the
+            // reveal phase, so those are closed as unsold instead.
+            assert!(
+                auction.highest_bid.is_some()
+                    || matches!(auction.auction_type, AuctionType::SealedBid(_)),
+                "No bids were made. Wait until at least 1 bid was made."
+            );
+
+            // For sealed bid auctions, return all deposits except the winner's and determine
+            // the price the winner pays. Any deposit above that price is returned as well.
+            if let AuctionType::SealedBid(sealed_bid_auction) = auction.auction_type.clone() {
+                let mut winner_deposit = dec!(0);
+                let mut second_highest_bid: Option<Decimal> = None;
+
+                let sealed_bids = self.sealed_bids.remove(&auction_id).unwrap_or_default();
+                for sealed_bid in sealed_bids {
+                    if Some(sealed_bid.bidder) == auction.highest_bidder {
+                        winner_deposit = sealed_bid.deposit;
+                        continue;
+                    }
+
+                    if let Some(amount) = sealed_bid.revealed_amount {
+                        second_highest_bid = Some(second_highest_bid.map_or(amount, |bid| bid.max(amount)));
+                    }
+
+                    let deposit = self
+                        .bid_vaults
+                        .get_mut(&auction_id)
+                        .unwrap()
+                        .take(sealed_bid.deposit);
+                    self.locker.store(sealed_bid.bidder, deposit.into(), true);
+                }
+
+                if let Some(highest_bid) = auction.highest_bid {
+                    if highest_bid >= auction.reserve_price {
+                        let price = match sealed_bid_auction.pricing {
+                            SealedBidPricing::FirstPrice => highest_bid,
+                            SealedBidPricing::SecondPrice => second_highest_bid
+                                .unwrap_or(auction.starting_price)
+                                .max(auction.reserve_price),
+                        };
+
+                        let excess_deposit = self
+                            .bid_vaults
+                            .get_mut(&auction_id)
+                            .unwrap()
+                            .take(winner_deposit - price);
+                        self.locker
+                            .store(auction.highest_bidder.unwrap(), excess_deposit.into(), true);
+                        auction.highest_bid = Some(price);
+                    }
+                }
+            }
+
+            // The proxy bid of the highest bidder is either refunded or settled below
+            self.proxy_max_bids.remove(&auction_id);
+
+            // If the reserve price was not met, close the auction as unsold. The highest bid is
+            // refunded and the NFT goes back to the end of the queue.
+            if auction
+                .highest_bid
+                .map_or(true, |highest_bid| highest_bid < auction.reserve_price)
+            {
+                if let Some(highest_bidder) = auction.highest_bidder {
+                    let refund = self.bid_vaults.get_mut(&auction_id).unwrap().take_all();
+                    self.locker.store(highest_bidder, refund.into(), true);
+                }
+                self.available_nfts_list.push(auction.nfts[0].clone());
+
+                auction.status = AuctionStatus::Unsold;
+                auction.settled_timestamp = Some(current_timestamp);
+                auction.settler = Some(account);
+                Runtime::emit_event(PiersRugyardAuctionUnsold {
+                    auction: auction.clone(),
+                });
+
+                self.complete_auction(auction);
+                self.close_auction(auction_id);
+
+                return (FungibleBucket::new(XRD), None);
+            }
+
+            // Take the winning bid and refund the unused part of a proxy bid
+            let mut highest_bid_bucket = self.bid_vaults.get_mut(&auction_id).unwrap().take_all();
+            let unused_max_bid = highest_bid_bucket.amount() - auction.highest_bid.unwrap();
+            if unused_max_bid > dec!(0) {
+                let refund = highest_bid_bucket.take(unused_max_bid);
+                self.locker
+                    .store(auction.highest_bidder.unwrap(), refund.into(), true);
+            }
+
+            // Take the reward for the account calling this method
+            let reward = self.settler_reward.reward_for(highest_bid_bucket.amount());
+            let reward_bucket = highest_bid_bucket
+                .take_advanced(reward, WithdrawStrategy::Rounded(RoundingMode::ToZero));
+
+            // Split the rest of the proceeds over the configured destinations. This happens
+            // before the NFTs leave the vault, so the winner gets no dividends from their own
+            // payment.
+            let total_early_bought = self.total_early_bought;
+            self.split_profits(auction.id, highest_bid_bucket);
+
+            // Deposit the NFTs to the winner. If the current caller is the winner, give them directly
+            let nft = self
+                .available_nfts_vault
+                .take_non_fungibles(&auction.nfts.iter().cloned().collect());
+            self.lots.remove(&auction.nfts[0]);
+
+            // New holders only get dividends from now on
+            for id in &auction.nfts {
+                self.dividend_checkpoints.insert(id.clone(), self.dividend_per_nft);
+            }
+
+            let mut nft_bucket: Option<NonFungibleBucket> = None;
+            if auction.highest_bidder.unwrap() == account {
+                nft_bucket = Some(nft);
+            } else {
+                self.locker
+                .store(auction.highest_bidder.unwrap(), nft.into(), true);
+            }
+
+            // Settle the auction
+            auction.status = AuctionStatus::Settled;
+            auction.settled_timestamp = Some(current_timestamp);
+            auction.settler = Some(account);
+            auction.settler_reward = reward_bucket.amount();
+            // Everything split_profits bought, before burns and dividends
+            auction.early_received = self.total_early_bought - total_early_bought;
+            auction.final_price = auction.highest_bid;
+
+            Runtime::emit_event(PiersRugyardAuctionSettled {
+                auction: auction.clone(),
+            });
+
+            self.complete_auction(auction);
+            self.close_auction(auction_id);
+
+            (reward_bucket, nft_bucket)
+        }
+
+        /// Splits the proceeds of an auction according to the profit split. The last share
+        /// gets whatever is left, so no dust stays behind.
+        fn split_profits(&mut self, auction_id: u64, mut proceeds: FungibleBucket) {
+            let total_amount = proceeds.amount();
+            let mut shares = Vec::new();
+
+            for (index, share) in self.profit_split.clone().into_iter().enumerate() {
+                let share_bucket = if index == self.profit_split.len() - 1 {
+                    proceeds.take(proceeds.amount())
+                } else {
+                    let amount = total_amount
+                        .checked_mul(share.percentage)
+                        .expect("Couldn't calculate profit share!");
+                    proceeds.take_advanced(amount, WithdrawStrategy::Rounded(RoundingMode::ToZero))
+                };
+                shares.push((share.destination.clone(), share_bucket.amount()));
+                if share_bucket.is_empty() {
+                    continue;
+                }
+
+                match share.destination {
+                    ProfitDestination::KeepXrd => self.xrd_profits_vault.put(share_bucket),
+                    ProfitDestination::SwapToEarly => {
+                        // Keep the XRD in the treasury for a later batch swap if swaps are deferred
+                        if self.deferred_swaps.is_some() {
+                            self.xrd_treasury_vault.put(share_bucket);
+                        } else if let Some(early_bucket) = self.swap_for_early(share_bucket, false) {
+                            self.deposit_early(early_bucket);
+                        }
+                    }
+                    ProfitDestination::Account(account) => {
+                        self.locker.store(account, share_bucket.into(), true);
+                    }
+                    ProfitDestination::Burn => {
+                        // Keep the XRD in the treasury as pending burn if swaps are deferred
+                        if self.deferred_swaps.is_some() {
+                            self.pending_burn_xrd += share_bucket.amount();
+                            self.xrd_treasury_vault.put(share_bucket);
+                        } else {
+                            self.swap_for_early(share_bucket, true);
+                        }
+                    }
+                }
+            }
+
+            Runtime::emit_event(PiersRugyardProfitSplit { auction_id, shares });
+        }
+
+        /// Swaps XRD for EARLY and returns the EARLY. The configured burn percentage of the
+        /// EARLY is burned first, or all of it if `burn_all` is set. If swap protection is
+        /// configured and the pool would return less than the minimum output, the XRD is kept in
+        /// the XRD treasury instead and None is returned. Any XRD the pool doesn't use is kept
+        /// there as well. If the actual swap returns less than the minimum output for the XRD
+        /// that was used, the transaction fails.
+        fn swap_for_early(&mut self, xrd_bucket: FungibleBucket, burn_all: bool) -> Option<FungibleBucket> {
+            let mut xrd_amount = xrd_bucket.amount();
+
+            if let Some(swap_protection) = &self.swap_protection {
+                let expected_early_amount = self.swap_adapter.quote(
+                    self.early_pool,
+                    XRD,
+                    xrd_amount,
+                    self.early_address,
+                );
+                let minimum_early_amount = swap_protection.minimum_output(xrd_amount);
+
+                if expected_early_amount < minimum_early_amount {
+                    Runtime::emit_event(PiersRugyardSwapSkipped {
+                        xrd_amount,
+                        expected_early_amount,
+                        minimum_early_amount,
+                    });
+
+                    if burn_all {
+                        self.pending_burn_xrd += xrd_amount;
+                    }
+                    self.xrd_treasury_vault.put(xrd_bucket);
+                    return None;
+                }
+            }
+
+            let (early_bucket, remainder) =
+                self.swap_adapter
+                    .swap(self.early_pool, xrd_bucket.into(), self.early_address);
+            let mut early_bucket = early_bucket.as_fungible();
+            let early_amount = early_bucket.amount();
+
+            // Keep what the pool didn't use for a later swap
+            if let Some(remainder) = remainder {
+                xrd_amount -= remainder.amount();
+                if burn_all {
+                    self.pending_burn_xrd += remainder.amount();
+                }
+                self.xrd_treasury_vault.put(remainder.as_fungible());
+            }
+
+            // The quote can differ from the actual swap, so check the output as well
+            if let Some(swap_protection) = &self.swap_protection {
+                assert!(
+                    early_amount >= swap_protection.minimum_output(xrd_amount),
+                    "Swap returned less than the minimum output!"
+                );
+            }
+
+            // Burn part of the EARLY
+            let burn_percentage = if burn_all {
+                dec!(1)
+            } else {
+                self.early_burn
+                    .as_ref()
+                    .map_or(dec!(0), |early_burn| early_burn.percentage)
+            };
+            let burn_amount = early_amount
+                .checked_mul(burn_percentage)
+                .expect("Couldn't calculate burn amount!");
+            let burn_bucket = early_bucket
+                .take_advanced(burn_amount, WithdrawStrategy::Rounded(RoundingMode::ToZero));
+            let early_burned = burn_bucket.amount();
+            if !burn_bucket.is_empty() {
+                self.burn_early(burn_bucket);
+            }
+
+            Runtime::emit_event(PiersRugyardEarlyBuy {
+                xrd_amount,
+                early_amount,
+                early_burned,
+            });
+
+            self.total_early_bought += early_amount;
+
+            Some(early_bucket)
+        }
+
+        /// Deposits purchased EARLY into the EARLY vault. The dividend share is credited to the
+        /// NFTs held outside of the component instead.
+        fn deposit_early(&mut self, mut early_bucket: FungibleBucket) {
+            let holder_count = self
+                .nft_manager
+                .total_supply()
+                .expect("Couldn't get NFT supply!")
+                - self.available_nfts_vault.amount();
+
+            if self.dividend_percentage > dec!(0) && holder_count > dec!(0) {
+                let dividend_per_nft = early_bucket
+                    .amount()
+                    .checked_mul(self.dividend_percentage)
+                    .and_then(|amount| amount.checked_div(holder_count))
+                    .expect("Couldn't calculate dividend!");
+                let dividend_bucket = early_bucket.take_advanced(
+                    dividend_per_nft * holder_count,
+                    WithdrawStrategy::Rounded(RoundingMode::ToZero),
+                );
+
+                self.dividend_per_nft += dividend_per_nft;
+                self.dividend_vault.put(dividend_bucket);
+            }
+
+            self.early_vault.put(early_bucket);
+        }
+
+        /// Burns EARLY, or sends it to the burn component if one is configured
+        fn burn_early(&mut self, early_bucket: FungibleBucket) {
+            self.total_early_burned += early_bucket.amount();
+
+            match self
+                .early_burn
+                .as_ref()
+                .and_then(|early_burn| early_burn.burn_component)
+            {
+                Some(burn_component) => {
+                    let burn_component: Global<AnyComponent> = Global::from(burn_component);
+                    burn_component.call_raw::<()>("deposit", scrypto_args!(early_bucket));
+                }
+                None => early_bucket.burn(),
+            }
+        }
+
+        /// Gets an active auction by its id
+        fn get_active_auction(&self, auction_id: u64) -> Auction {
+            self.active_auctions
+                .get(&auction_id)
+                .expect("Auction is not active!")
+                .clone()
+        }
+
+        /// Removes an auction from the active auctions and starts a new auction if possible
+        fn close_auction(&mut self, auction_id: u64) {
+            self.remove_active_auction(auction_id);
+
+            let can_start_now = self.next_start_timestamp().map_or(true, |timestamp| {
+                Clock::current_time_is_at_or_after(timestamp, TimePrecisionV2::Second)
+            });
+
+            if !self.available_nfts_list.is_empty()
+                && self.active
+                && (self.active_auction_ids.len() as u64) < self.max_active_auctions
+                && can_start_now
+            {
+                self.start_new_auction();
+            }
+        }
+
+        /// Adds a bid to the full bid history of the auction, keyed by auction id and bid
+        /// index, and to the latest bids on the auction itself
+        fn add_bid(&mut self, auction: &mut Auction, bid: Bid) {
+            self.bids.insert((auction.id, auction.bid_count), bid.clone());
+            auction.add_bid(bid);
+        }
+
+        /// Stores a settled, unsold or cancelled auction in the completed auctions and adds it
+        /// to the lookups by NFT and, if it was sold, by winner
+        fn complete_auction(&mut self, auction: Auction) {
+            for id in &auction.nfts {
+                let count = self.nft_auction_counts.get(id).map_or(0, |count| *count);
+                self.nft_auction_ids.insert((id.clone(), count), auction.id);
+                self.nft_auction_counts.insert(id.clone(), count + 1);
+            }
+
+            if auction.status == AuctionStatus::Settled {
+                let winner = auction.highest_bidder.unwrap().address();
+                let count = self.winner_auction_counts.get(&winner).map_or(0, |count| *count);
+                self.winner_auction_ids.insert((winner, count), auction.id);
+                self.winner_auction_counts.insert(winner, count + 1);
+            }
+
+            self.completed_auctions.insert(auction.id, auction);
+        }
+
+        /// Makes sure the component is not paused
+        fn assert_not_paused(&self) {
+            assert!(self.paused_at.is_none(), "Component is paused!");
+        }
+
+        /// Removes an auction from the active auctions. The schedule for the next auction counts
+        /// from the end of this auction, or from now if it was cancelled before its end.
+        fn remove_active_auction(&mut self, auction_id: u64) {
+            let auction = self
+                .active_auctions
+                .remove(&auction_id)
+                .expect("Auction is not active!");
+            self.active_auction_ids.retain(|id| *id != auction_id);
+            self.last_auction_end_timestamp = Some(
+                auction
+                    .end_timestamp
+                    .min(Clock::current_time(TimePrecisionV2::Second)),
+            );
+        }
+
+        /// Returns the auction config overrides for an NFT
+        fn get_nft_auction_config(&self, id: &NonFungibleLocalId) -> AuctionConfig {
+            self.nft_auction_configs
+                .get(id)
+                .map(|auction_config| auction_config.clone())
+                .unwrap_or_default()
+        }
+
+        /// Validates the auction config overrides of all queued NFTs and NFTs in active auctions
+        /// against new default settings, so an auction can't start with an invalid config later
+        fn validate_nft_auction_configs(&self, default_duration_minutes: u64, default_buffer_minutes: u64) {
+            let active_nfts: Vec<NonFungibleLocalId> = self
+                .active_auction_ids
+                .iter()
+                .flat_map(|auction_id| self.active_auctions.get(auction_id).unwrap().nfts.clone())
+                .collect();
+
+            for id in self.available_nfts_list.iter().chain(active_nfts.iter()) {
+                if let Some(auction_config) = self.nft_auction_configs.get(id) {
+                    auction_config.validate(default_duration_minutes, default_buffer_minutes);
+                }
+            }
+        }
+
+        /// Returns the earliest time the next auction can start according to the schedule,
+        /// or None if it can start right away. Before any auction has ended, this is the first
+        /// daily start time after the schedule was set.
+        fn next_start_timestamp(&self) -> Option<Instant> {
+            match self.last_auction_end_timestamp {
+                Some(timestamp) => Some(self.auction_schedule.next_start(timestamp)),
+                None => self.first_start_timestamp,
+            }
+        }
+
+        //------ Admin stuff ------//
+
+        /// Withdraws the profits from the EARLY vault
+        pub fn withdraw_profits(&mut self) -> FungibleBucket {
+            self.early_vault.take_all()
+        }
+
+        /// Updates the pool used for swapping, together with the interface of its DEX
+        ///
+        /// # Input
+        /// * `adapter`: a SwapAdapter for the interface of the pool
+        /// * `address`: a ComponentAddress of the new pool
+        pub fn update_swap_pool(&mut self, adapter: SwapAdapter, address: ComponentAddress) {
+            self.swap_adapter = adapter.clone();
+            self.early_pool = address;
+
+            Runtime::emit_event(PiersRugyardConfigUpdated {
+                update: ConfigUpdate::SwapPool(adapter, address),
+            });
+        }
+
+        /// Sets or removes the protection for the EARLY swap. When set, the swap is skipped
+        /// and the XRD is kept in the XRD treasury if the pool would return less than
+        /// the reference price minus the maximum slippage.
+        ///
+        /// # Input
+        /// * `swap_protection`: an Option<SwapProtection> with the reference price in EARLY per XRD
+        ///   and the maximum slippage, or None to always swap
+        ///
+        /// # Panics
+        /// * The reference price is 0 or lower
+        /// * The maximum slippage is not between 0 and 1
+        pub fn update_swap_protection(&mut self, swap_protection: Option<SwapProtection>) {
+            if let Some(swap_protection) = &swap_protection {
+                assert!(
+                    swap_protection.reference_price > dec!(0),
+                    "Reference price must be higher than 0!"
+                );
+                assert!(
+                    swap_protection.max_slippage >= dec!(0) && swap_protection.max_slippage < dec!(1),
+                    "Maximum slippage must be between 0 and 1!"
+                );
+            }
+
+            self.swap_protection = swap_protection.clone();
+
+            Runtime::emit_event(PiersRugyardConfigUpdated {
+                update: ConfigUpdate::SwapProtection(swap_protection),
+            });
+        }
+
+        /// Withdraws all XRD from the XRD treasury, including the XRD pending burn
+        pub fn withdraw_treasury_xrd(&mut self) -> FungibleBucket {
+            self.pending_burn_xrd = dec!(0);
+            self.xrd_treasury_vault.take_all()
+        }
+
+        /// Turns deferred swapping on or off. With deferred swapping, auction proceeds are kept
+        /// in the XRD treasury and swapped in batches with `execute_swap`.
+        ///
+        /// # Input
+        /// * `deferred_swaps`: an Option<DeferredSwaps> with the bounty for the caller of
+        ///   `execute_swap` and an optional maximum amount per swap, or None to swap immediately
+        ///
+        /// # Panics
+        /// * The bounty percentage is lower than 0 or higher than 5%
+        /// * The maximum swap amount is 0 or lower
+        pub fn update_deferred_swaps(&mut self, deferred_swaps: Option<DeferredSwaps>) {
+            if let Some(deferred_swaps) = &deferred_swaps {
+                assert!(
+                    deferred_swaps.bounty_percentage >= dec!(0)
+                        && deferred_swaps.bounty_percentage <= dec!(0.05),
+                    "Bounty percentage must be between 0 and 5%!"
+                );
+                assert!(
+                    deferred_swaps
+                        .max_swap_amount
+                        .map_or(true, |max_swap_amount| max_swap_amount > dec!(0)),
+                    "Maximum swap amount must be higher than 0!"
+                );
+            }
+
+            self.deferred_swaps = deferred_swaps.clone();
+
+            Runtime::emit_event(PiersRugyardConfigUpdated {
+                update: ConfigUpdate::DeferredSwaps(deferred_swaps),
+            });
+        }
+
+        /// Swaps XRD from the treasury for EARLY. XRD pending burn is swapped first and the
+        /// EARLY it buys is burned. The caller gets the configured bounty out of
+        /// the swapped amount. If the swap is skipped because of the swap protection, the XRD
+        /// stays in the treasury and no bounty is paid. This method can be called by the keeper
+        /// or the owner.
+        ///
+        /// # Input
+        /// * `amount`: a Decimal with the amount of XRD to swap
+        ///
+        /// # Output
+        /// * A FungibleBucket with the bounty
+        ///
+        /// # Panics
+        /// * Component is paused
+        /// * The amount is 0 or lower
+        /// * The amount is higher than the treasury holds
+        /// * The amount is higher than the maximum swap amount
+        pub fn execute_swap(&mut self, amount: Decimal) -> FungibleBucket {
+            self.assert_not_paused();
+
+            assert!(amount > dec!(0), "Amount must be higher than 0!");
+            assert!(
+                amount <= self.xrd_treasury_vault.amount(),
+                "Not enough XRD in the treasury!"
+            );
+
+            let (bounty_percentage, max_swap_amount) = self
+                .deferred_swaps
+                .as_ref()
+                .map_or((dec!(0), None), |deferred_swaps| {
+                    (deferred_swaps.bounty_percentage, deferred_swaps.max_swap_amount)
+                });
+            assert!(
+                max_swap_amount.map_or(true, |max_swap_amount| amount <= max_swap_amount),
+                "Amount is higher than the maximum swap amount!"
+            );
+
+            let mut xrd_bucket = self.xrd_treasury_vault.take(amount);
+            let bounty = amount
+                .checked_mul(bounty_percentage)
+                .expect("Couldn't calculate bounty!");
+            let bounty_bucket =
+                xrd_bucket.take_advanced(bounty, WithdrawStrategy::Rounded(RoundingMode::ToZero));
+
+            // XRD pending burn is swapped and burned first. Skipped swaps put it back as pending.
+            let burn_amount = self.pending_burn_xrd.min(amount);
+            self.pending_burn_xrd -= burn_amount;
+            let burn_bucket = xrd_bucket.take(burn_amount.min(xrd_bucket.amount()));
+            let burn_bounty = burn_amount - burn_bucket.amount();
+
+            let mut swapped = false;
+            if burn_bucket.is_empty() {
+                self.xrd_treasury_vault.put(burn_bucket);
+            } else {
+                swapped |= self.swap_for_early(burn_bucket, true).is_some();
+            }
+            if xrd_bucket.is_empty() {
+                self.xrd_treasury_vault.put(xrd_bucket);
+            } else if let Some(early_bucket) = self.swap_for_early(xrd_bucket, false) {
+                self.deposit_early(early_bucket);
+                swapped = true;
+            }
+
+            if swapped {
+                bounty_bucket
+            } else {
+                self.pending_burn_xrd += burn_bounty;
+                self.xrd_treasury_vault.put(bounty_bucket);
+                FungibleBucket::new(XRD)
+            }
+        }
+
+        /// Updates how the proceeds of an auction are split after the settler reward. Each share
+        /// is kept as XRD, swapped to EARLY, sent to an account or used to buy EARLY that is burned.
+        ///
+        /// # Input
+        /// * `profit_split`: a Vec with the ProfitShares, whose percentages add up to 1
+        ///
+        /// # Panics
+        /// * There are no shares or more than 10 shares
+        /// * A percentage is 0 or lower
+        /// * The percentages don't add up to 1
+        pub fn update_profit_split(&mut self, profit_split: Vec<ProfitShare>) {
+            assert!(
+                !profit_split.is_empty() && profit_split.len() <= 10,
+                "Profit split must have between 1 and 10 shares!"
+            );
+            assert!(
+                profit_split.iter().all(|share| share.percentage > dec!(0)),
+                "Profit share percentage must be higher than 0!"
+            );
+            assert!(
+                profit_split
+                    .iter()
+                    .fold(dec!(0), |total, share| total + share.percentage)
+                    == dec!(1),
+                "Profit share percentages must add up to 1!"
+            );
+
+            self.profit_split = profit_split.clone();
+
+            Runtime::emit_event(PiersRugyardConfigUpdated {
+                update: ConfigUpdate::ProfitSplit(profit_split),
+            });
+        }
+
+        /// Sets or removes the share of purchased EARLY that is burned. If EARLY can't be burned by
+        /// this component, a burn component can be set that the EARLY is deposited into instead.
+        ///
+        /// # Input
+        /// * `early_burn`: an Option<EarlyBurn> with the burn percentage and optional burn
+        ///   component, or None to keep all EARLY
+        ///
+        /// # Panics
+        /// * The percentage is not higher than 0 and at most 1
+        pub fn update_early_burn(&mut self, early_burn: Option<EarlyBurn>) {
+            if let Some(early_burn) = &early_burn {
+                assert!(
+                    early_burn.percentage > dec!(0) && early_burn.percentage <= dec!(1),
+                    "Burn percentage must be higher than 0 and at most 1!"
+                );
+            }
+
+            self.early_burn = early_burn.clone();
+
+            Runtime::emit_event(PiersRugyardConfigUpdated {
+                update: ConfigUpdate::EarlyBurn(early_burn),
+            });
+        }
+
+        /// Updates the share of purchased EARLY that is paid out as dividends to the NFT holders
+        ///
+        /// # Input
+        /// * `dividend_percentage`: a Decimal for the share, e.g. 0.1 for 10%
+        ///
+        /// # Panics
+        /// * The percentage is lower than 0 or higher than 1
+        pub fn update_dividend_percentage(&mut self, dividend_percentage: Decimal) {
+            assert!(
+                dividend_percentage >= dec!(0) && dividend_percentage <= dec!(1),
+                "Dividend percentage must be between 0 and 1!"
+            );
+
+            self.dividend_percentage = dividend_percentage;
+
+            Runtime::emit_event(PiersRugyardConfigUpdated {
+                update: ConfigUpdate::DividendPercentage(dividend_percentage),
+            });
+        }
+
+        /// Withdraws the profits that were kept as XRD
+        pub fn withdraw_xrd_profits(&mut self) -> FungibleBucket {
+            self.xrd_profits_vault.take_all()
+        }
+
+        /// Updates the auction duration
+        ///
+        /// # Input
+        /// * `minutes`: a u64 for the new auction duration in minutes
+        ///
+        /// # Panics
+        /// * Duration is 0 or lower
+        /// * Duration is shorter or equal to time buffer
+        /// * Duration is shorter or equal to the buffer override of an NFT
+        pub fn update_auction_duration(&mut self, minutes: u64) {
+            assert!(minutes > 0, "Auction duration must be more than 0 minutes!");
+            assert!(
+                minutes > self.auction_buffer_minutes,
+                "Auction duration must be longer than the auction buffer!"
+            );
+            self.validate_nft_auction_configs(minutes, self.auction_buffer_minutes);
+
+            self.auction_duration_minutes = minutes;
+        }
+
+        /// Updates the auction buffer. Active auctions are updated as well, except for auctions
+        /// of NFTs that override the buffer.
+        ///
+        /// # Input
+        /// * `minutes`: a u64 for the new time buffer in minutes
+        ///
+        /// # Panics
+        /// * The buffer is 0 or lower
+        /// * The buffer is higher than the auction duration
+        /// * The buffer is higher than or equal to the duration override of an NFT
+        pub fn update_auction_buffer(&mut self, minutes: u64) {
+            assert!(minutes > 0, "Buffer must be more than 0 minutes!");
+            assert!(
+                minutes < self.auction_duration_minutes,
+                "Buffer must be lower than the auction duration!"
+            );
+            self.validate_nft_auction_configs(self.auction_duration_minutes, minutes);
+
+            self.auction_buffer_minutes = minutes;
+            for auction_id in self.active_auction_ids.clone() {
+                let mut auction = self.get_active_auction(auction_id);
+                let has_override = self
+                    .get_nft_auction_config(&auction.nfts[0])
+                    .buffer_minutes
+                    .is_some();
+
+                if !has_override {
+                    auction.buffer_minutes = minutes;
+                    self.active_auctions.insert(auction_id, auction);
+                }
+            }
+        }
+
+        /// Updates the schedule for starting auctions. After an auction ends, the next auction can
+        /// only start after the cooldown and, if a daily start time is set, at the next daily
+        /// start time. If no auction has ended yet, the first auction can only start at the next
+        /// daily start time.
+        ///
+        /// # Input
+        /// * `auction_schedule`: an AuctionSchedule with the optional daily start time in minutes
+        ///   after midnight UTC and the optional cooldown in minutes
+        ///
+        /// # Panics
+        /// * The daily start time is not within a day
+        pub fn update_auction_schedule(&mut self, auction_schedule: AuctionSchedule) {
+            assert!(
+                auction_schedule
+                    .daily_start_minutes
+                    .map_or(true, |minutes| minutes < 24 * 60),
+                "Daily start time must be within a day!"
+            );
+
+            self.auction_schedule = auction_schedule.clone();
+            self.first_start_timestamp =
+                auction_schedule.first_start(Clock::current_time(TimePrecisionV2::Second));
+
+            Runtime::emit_event(PiersRugyardConfigUpdated {
+                update: ConfigUpdate::AuctionSchedule(auction_schedule),
+            });
+        }
+
+        /// Updates the limits on how often and how long bids can extend an auction. The limits
+        /// apply to auctions that start after the update.
+        ///
+        /// # Input
+        /// * `extension_limits`: an ExtensionLimits with the optional maximum number of extensions
+        ///   and the optional maximum total extension in minutes
+        pub fn update_extension_limits(&mut self, extension_limits: ExtensionLimits) {
+            self.extension_limits = extension_limits.clone();
+
+            Runtime::emit_event(PiersRugyardConfigUpdated {
+                update: ConfigUpdate::ExtensionLimits(extension_limits),
+            });
+        }
+
+        /// Upates the minimum bid increase. Active auctions are updated as well, except for
+        /// auctions of NFTs that override the minimum bid increase.
+        ///
+        /// # Input
+        /// * `minimum_bid_increase`: A Decimal for the new minimum bid increase
+        ///
+        /// # Panics
+        /// * The minimum bid increase is 0 or lower
+        pub fn update_minimum_bid_increase(&mut self, minimum_bid_increase: Decimal) {
+            assert!(
+                minimum_bid_increase > dec!(0),
+                "Minimum bid increase must be higher than 0!"
+            );
+
+            self.minimum_bid_increase = minimum_bid_increase;
+            for auction_id in self.active_auction_ids.clone() {
+                let mut auction = self.get_active_auction(auction_id);
+                let has_override = self
+                    .get_nft_auction_config(&auction.nfts[0])
+                    .minimum_bid_increase
+                    .is_some();
+
+                if !has_override {
+                    auction.minimum_bid_increase = minimum_bid_increase;
+                    self.active_auctions.insert(auction_id, auction);
+                }
+            }
+        }
+
+        /// Updates the minimum bid increase as a percentage of the highest bid. A new bid must
+        /// raise the highest bid by the minimum bid increase or this percentage of the highest
+        /// bid, whichever is higher. Active auctions are updated as well.
+        ///
+        /// # Input
+        /// * `percentage`: A Decimal for the new percentage, e.g. 0.05 for 5%
+        ///
+        /// # Panics
+        /// * The percentage is lower than 0 or higher than 1
+        pub fn update_minimum_bid_increase_percentage(&mut self, percentage: Decimal) {
+            assert!(
+                percentage >= dec!(0) && percentage <= dec!(1),
+                "Minimum bid increase percentage must be between 0 and 1!"
+            );
+
+            self.minimum_bid_increase_percentage = percentage;
+            for auction_id in &self.active_auction_ids {
+                self.active_auctions
+                    .get_mut(auction_id)
+                    .unwrap()
+                    .minimum_bid_increase_percentage = percentage;
+            }
+        }
+
+        /// Updates the reward for settling an auction. The reward is a percentage of the
+        /// winning bid, optionally kept between a floor and a cap in XRD.
+        ///
+        /// # Input
+        /// * `percentage`: A Decimal for the share of the winning bid, e.g. 0.05 for 5%
+        /// * `cap`: An Option<Decimal> with the maximum reward in XRD
+        /// * `floor`: An Option<Decimal> with the minimum reward in XRD
+        ///
+        /// # Panics
+        /// * The percentage is lower than 0 or higher than 20%
+        /// * The cap or floor is lower than 0
+        /// * The floor is higher than the cap
+        pub fn update_settler_reward(&mut self, percentage: Decimal, cap: Option<Decimal>, floor: Option<Decimal>) {
+            assert!(
+                percentage >= dec!(0) && percentage <= dec!(0.2),
+                "Settler reward percentage must be between 0 and 20%!"
+            );
+            assert!(
+                cap.map_or(true, |cap| cap >= dec!(0)),
+                "Settler reward cap can't be lower than 0!"
+            );
+            assert!(
+                floor.map_or(true, |floor| floor >= dec!(0)),
+                "Settler reward floor can't be lower than 0!"
+            );
+            if let (Some(cap), Some(floor)) = (cap, floor) {
+                assert!(floor <= cap, "Settler reward floor can't be higher than the cap!");
+            }
+
+            self.settler_reward = SettlerReward {
+                percentage,
+                cap,
+                floor,
+            };
+
+            Runtime::emit_event(PiersRugyardConfigUpdated {
+                update: ConfigUpdate::SettlerReward(self.settler_reward.clone()),
+            });
+        }
+
+        /// Updates the default reserve price. Auctions that end with a highest bid below
+        /// the reserve price are closed as unsold.
+        ///
+        /// # Input
+        /// * `reserve_price`: A Decimal for the new default reserve price
+        ///
+        /// # Panics
+        /// * The reserve price is lower than 0
+        pub fn update_reserve_price(&mut self, reserve_price: Decimal) {
+            assert!(
+                reserve_price >= dec!(0),
+                "Reserve price can't be lower than 0!"
+            );
+
+            self.reserve_price = reserve_price;
+        }
+
+        /// Sets or removes the reserve price override for a specific NFT. The override is
+        /// applied when an auction for the NFT starts.
+        ///
+        /// # Input
+        /// * `id`: A NonFungibleLocalId for the NFT
+        /// * `reserve_price`: An Option<Decimal> with the reserve price, or None to use the default
+        ///
+        /// # Panics
+        /// * NFT is not available
+        /// * The reserve price is lower than 0
+        pub fn set_nft_reserve_price(&mut self, id: NonFungibleLocalId, reserve_price: Option<Decimal>) {
+            assert!(
+                self.available_nfts_list.contains(&id),
+                "NFT is not available!"
+            );
+
+            let mut auction_config = self.get_nft_auction_config(&id);
+            auction_config.reserve_price = reserve_price;
+            auction_config.validate(self.auction_duration_minutes, self.auction_buffer_minutes);
+            self.nft_auction_configs.insert(id, auction_config);
+        }
+
+        /// Updates the default starting price. The first bid on an auction must be at
+        /// least the starting price.
+        ///
+        /// # Input
+        /// * `starting_price`: A Decimal for the new default starting price
+        ///
+        /// # Panics
+        /// * The starting price is 0 or lower
+        pub fn update_starting_price(&mut self, starting_price: Decimal) {
+            assert!(
+                starting_price > dec!(0),
+                "Starting price must be higher than 0!"
+            );
+
+            self.starting_price = starting_price;
+        }
+
+        /// Sets or removes the starting price override for a specific NFT. The override is
+        /// applied when an auction for the NFT starts.
+        ///
+        /// # Input
+        /// * `id`: A NonFungibleLocalId for the NFT
+        /// * `starting_price`: An Option<Decimal> with the starting price, or None to use the default
+        ///
+        /// # Panics
+        /// * NFT is not available
+        /// * The starting price is 0 or lower
+        pub fn set_nft_starting_price(&mut self, id: NonFungibleLocalId, starting_price: Option<Decimal>) {
+            assert!(
+                self.available_nfts_list.contains(&id),
+                "NFT is not available!"
+            );
+
+            let mut auction_config = self.get_nft_auction_config(&id);
+            auction_config.starting_price = starting_price;
+            auction_config.validate(self.auction_duration_minutes, self.auction_buffer_minutes);
+            self.nft_auction_configs.insert(id, auction_config);
+        }
+
+        /// Sets or removes all auction config overrides for a specific NFT. The overrides are
+        /// applied when an auction for the NFT starts.
+        ///
+        /// # Input
+        /// * `id`: A NonFungibleLocalId for the NFT
+        /// * `auction_config`: An Option<AuctionConfig> with the overrides, or None to use the defaults
+        ///
+        /// # Panics
+        /// * NFT is not available
+        /// * The auction config is invalid
+        pub fn set_nft_auction_config(&mut self, id: NonFungibleLocalId, auction_config: Option<AuctionConfig>) {
+            assert!(
+                self.available_nfts_list.contains(&id),
+                "NFT is not available!"
+            );
+
+            match auction_config {
+                Some(auction_config) => {
+                    auction_config.validate(self.auction_duration_minutes, self.auction_buffer_minutes);
+                    self.nft_auction_configs.insert(id, auction_config);
+                }
+                None => {
+                    self.nft_auction_configs.remove(&id);
+                }
+            }
+        }
+
+        /// Sets the auction type for a specific NFT. When an auction for the NFT starts, it
+        /// will be an auction of this type. NFTs are auctioned in a regular English auction
+        /// by default.
+        ///
+        /// # Input
+        /// * `id`: A NonFungibleLocalId for the NFT
+        /// * `auction_type`: An AuctionType with the auction settings
+        ///
+        /// # Panics
+        /// * NFT is not available
+        /// * The Dutch auction floor price is lower than 0
+        /// * The Dutch auction start price is not higher than the floor price
+        /// * The Dutch auction floor price is lower than the reserve price
+        /// * The Dutch auction price decay settings are invalid
+        /// * The sealed bid reveal duration is 0
+        pub fn set_nft_auction_type(&mut self, id: NonFungibleLocalId, auction_type: AuctionType) {
+            assert!(
+                self.available_nfts_list.contains(&id),
+                "NFT is not available!"
+            );
+
+            match &auction_type {
+                AuctionType::English => {
+                    self.nft_auction_types.remove(&id);
+                    return;
+                }
+                AuctionType::Dutch(dutch_auction) => {
+                    assert!(
+                        dutch_auction.floor_price >= dec!(0),
+                        "Floor price can't be lower than 0!"
+                    );
+                    assert!(
+                        dutch_auction.start_price > dutch_auction.floor_price,
+                        "Start price must be higher than the floor price!"
+                    );
+                    assert!(
+                        dutch_auction.floor_price
+                            >= self
+                                .get_nft_auction_config(&id)
+                                .reserve_price
+                                .unwrap_or(self.reserve_price),
+                        "Floor price can't be lower than the reserve price!"
+                    );
+
+                    match dutch_auction.decay {
+                        PriceDecay::Linear => {}
+                        PriceDecay::Stepped { step_minutes } => assert!(
+                            step_minutes > 0,
+                            "Step must be more than 0 minutes!"
+                        ),
+                        PriceDecay::Exponential { decay_per_minute } => assert!(
+                            decay_per_minute > dec!(0) && decay_per_minute < dec!(1),
+                            "Decay per minute must be between 0 and 1!"
+                        ),
+                    }
+                }
+                AuctionType::SealedBid(sealed_bid_auction) => {
+                    assert!(
+                        sealed_bid_auction.reveal_duration_minutes > 0,
+                        "Reveal duration must be more than 0 minutes!"
+                    );
+                }
+            }
+
+            self.nft_auction_types.insert(id, auction_type);
+        }
+
+        /// Updates the maximum number of auctions that can run at the same time. Lowering it
+        /// doesn't affect auctions that are already running.
+        ///
+        /// # Input
+        /// * `max_active_auctions`: a u64 for the new maximum number of active auctions
+        ///
+        /// # Panics
+        /// * The maximum is 0
+        pub fn update_max_active_auctions(&mut self, max_active_auctions: u64) {
+            assert!(
+                max_active_auctions > 0,
+                "Maximum number of active auctions must be more than 0!"
+            );
+
+            self.max_active_auctions = max_active_auctions;
+        }
+
+        /// Activates or deactives the auction system
+        pub fn flip_status(&mut self) {
+            self.active = !self.active;
+        }
+
+        /// Pauses the component in case of an emergency. While paused, no auctions can start
+        /// and bidding, settling and swapping are blocked. This method can be called by the
+        /// pauser or the owner.
+        ///
+        /// # Panics
+        /// * Component is already paused
+        pub fn pause(&mut self) {
+            self.assert_not_paused();
+
+            let timestamp = Clock::current_time(TimePrecisionV2::Second);
+            self.paused_at = Some(timestamp);
+
+            Runtime::emit_event(PiersRugyardPaused { timestamp });
+        }
+
+        /// Unpauses the component. All active auctions are shifted by the time the component
+        /// was paused, so bidders don't lose any time and Dutch auction prices don't drop
+        /// while paused. This method can be called by the pauser
+        /// or the owner.
+        ///
+        /// # Panics
+        /// * Component is not paused
+        pub fn unpause(&mut self) {
+            let paused_at = self.paused_at.take().expect("Component is not paused!");
+            let timestamp = Clock::current_time(TimePrecisionV2::Second);
+            let paused_seconds = timestamp.seconds_since_unix_epoch - paused_at.seconds_since_unix_epoch;
+
+            for auction_id in self.active_auction_ids.clone() {
+                let mut auction = self.get_active_auction(auction_id);
+
+                auction.start_timestamp = auction
+                    .start_timestamp
+                    .add_seconds(paused_seconds)
+                    .expect("Could not calculate start timestamp");
+                auction.end_timestamp = auction
+                    .end_timestamp
+                    .add_seconds(paused_seconds)
+                    .expect("Could not calculate end timestamp");
+                auction.commit_end_timestamp = auction.commit_end_timestamp.map(|commit_end_timestamp| {
+                    commit_end_timestamp
+                        .add_seconds(paused_seconds)
+                        .expect("Could not calculate commit end timestamp")
+                });
+                auction.hard_end_timestamp = auction.hard_end_timestamp.map(|hard_end_timestamp| {
+                    hard_end_timestamp
+                        .add_seconds(paused_seconds)
+                        .expect("Could not calculate hard end timestamp")
+                });
+
+                Runtime::emit_event(PiersRugyardAuctionExtended {
+                    auction_id,
+                    end_timestamp: auction.end_timestamp,
+                    extension_count: auction.extension_count,
+                });
+
+                self.active_auctions.insert(auction_id, auction);
+            }
+
+            Runtime::emit_event(PiersRugyardUnpaused {
+                timestamp,
+                paused_seconds,
+            });
+        }
+
+        /// Cancels an active auction. All bids and deposits are refunded through the account
+        /// locker and the NFTs are put back in the queue or burned. A new auction is not started
+        /// automatically, so the queue can be fixed first.
+        ///
+        /// # Input
+        /// * `auction_id`: a u64 with the id of the auction to cancel
+        /// * `placement`: a NftPlacement for where the NFTs go: the front or back of the queue, or burned
+        ///
+        /// # Panics
+        /// * Auction is not active
+        pub fn cancel_current_auction(&mut self, auction_id: u64, placement: NftPlacement) {
+            let mut auction = self.get_active_auction(auction_id);
+
+            // Refund all sealed bid deposits, or the highest bid including an unused proxy bid
+            let sealed_bids = self.sealed_bids.remove(&auction_id).unwrap_or_default();
+            for sealed_bid in sealed_bids {
+                let deposit = self
+                    .bid_vaults
+                    .get_mut(&auction_id)
+                    .unwrap()
+                    .take(sealed_bid.deposit);
+                self.locker.store(sealed_bid.bidder, deposit.into(), true);
+            }
+
+            self.proxy_max_bids.remove(&auction_id);
+            if let Some(highest_bidder) = auction.highest_bidder {
+                let refund = self.bid_vaults.get_mut(&auction_id).unwrap().take_all();
+                if !refund.is_empty() {
+                    self.locker.store(highest_bidder, refund.into(), true);
+                }
+            }
+
+            // Put the NFTs back in the queue. A lot stays together behind its lead NFT.
+            match placement {
+                NftPlacement::Front => self.available_nfts_list.insert(0, auction.nfts[0].clone()),
+                NftPlacement::Back => self.available_nfts_list.push(auction.nfts[0].clone()),
+                NftPlacement::Burn => {
+                    self.lots.remove(&auction.nfts[0]);
+                    for id in &auction.nfts {
+                        self.nft_auction_types.remove(id);
+                        self.nft_auction_configs.remove(id);
+                    }
+                    self.available_nfts_vault
+                        .take_non_fungibles(&auction.nfts.iter().cloned().collect())
+                        .burn();
+                }
+            }
+
+            auction.status = AuctionStatus::Cancelled;
+            Runtime::emit_event(PiersRugyardAuctionCancelled {
+                auction: auction.clone(),
+                placement,
+            });
+
+            self.complete_auction(auction);
+            self.remove_active_auction(auction_id);
+        }
+
+        /// Mints a new NFT for the collection and puts it in the list
+        /// of NFTs to be auctioned.
+        ///
+        /// # Input
+        /// * `nft_data`: a Vec with an NFT struct with the data for each new NFT and
+        ///   optional auction config overrides for its auction
+        ///
+        /// # Panics
+        /// * An auction config is invalid
+        pub fn mint_nfts(&mut self, nft_data: Vec<(NFT, Option<AuctionConfig>)>) {
+            for (data, auction_config) in nft_data {
+                let local_id = NonFungibleLocalId::integer(self.next_nft_id);
+                let nft = self.nft_manager.mint_non_fungible(&local_id, data.clone());
+
+                if let Some(auction_config) = auction_config {
+                    auction_config.validate(self.auction_duration_minutes, self.auction_buffer_minutes);
+                    self.nft_auction_configs.insert(local_id.clone(), auction_config);
+                }
+
+                // Put NFT in vault and in available NFTs list
+                self.available_nfts_list.push(local_id.clone());
+                self.available_nfts_vault.put(nft);
+
+                Runtime::emit_event(PiersRugyardMinted {
+                    id: local_id,
+                    nft_data: data,
+                });
+
+                // Increment the NFT id
+                self.next_nft_id += 1;
+            }
+        }
+
+        /// Removes an NFT from the available NFTs list and burns it. This method is protected.
+        ///
+        /// # Input
+        /// * `id`: A NonFungibleLocalId for the NFT to be deleted
+        ///
+        /// # Panics
+        /// * NFT does not exist
+        /// * NFT is currently under auction
+        pub fn delete_nft(&mut self, id: NonFungibleLocalId) {
+            assert!(
+                self.available_nfts_list.contains(&id),
+                "NFT is not available!"
+            );
+
+            for auction_id in &self.active_auction_ids {
+                assert!(
+                    !self.active_auctions.get(auction_id).unwrap().nfts.contains(&id),
                     "Can't delete an NFT that's currently under auction!"
                 );
             }
 
+            assert!(
+                self.lots.get(&id).is_none(),
+                "Can't delete an NFT that's part of a lot!"
+            );
+
             // Burn the NFT and remove it from the list
             let nft_position = self
                 .available_nfts_list
@@ -498,9 +2356,65 @@ mod piers_rugyard {
                 .position(|nft| nft == &id)
                 .expect("Could not find NFT!");
             self.available_nfts_list.remove(nft_position);
+            self.nft_auction_types.remove(&id);
+            self.nft_auction_configs.remove(&id);
             self.available_nfts_vault.take_non_fungible(&id).burn();
         }
 
+        /// Combines several available NFTs into a lot that is auctioned as a whole. The first
+        /// NFT is the lead NFT: it keeps its place in the queue and its settings are used for the
+        /// auction. The other NFTs are taken out of the queue.
+        ///
+        /// # Input
+        /// * `ids`: a Vec with the NonFungibleLocalIds of the NFTs in the lot
+        ///
+        /// # Panics
+        /// * There are less than 2 NFTs
+        /// * An NFT is not available
+        /// * An NFT is already part of a lot
+        pub fn create_lot(&mut self, ids: Vec<NonFungibleLocalId>) {
+            assert!(ids.len() >= 2, "A lot needs at least 2 NFTs!");
+
+            for (index, id) in ids.iter().enumerate() {
+                assert!(
+                    self.available_nfts_list.contains(id),
+                    "NFT is not available!"
+                );
+                assert!(
+                    self.lots.get(id).is_none(),
+                    "NFT is already part of a lot!"
+                );
+                assert!(
+                    !ids[..index].contains(id),
+                    "NFT is in the lot more than once!"
+                );
+            }
+
+            // Only the lead NFT stays in the queue
+            self.available_nfts_list
+                .retain(|id| *id == ids[0] || !ids.contains(id));
+            self.lots.insert(ids[0].clone(), ids);
+        }
+
+        /// Splits up a lot. The NFTs other than the lead NFT are put back at the end of the queue.
+        ///
+        /// # Input
+        /// * `id`: the NonFungibleLocalId of the lead NFT of the lot
+        ///
+        /// # Panics
+        /// * NFT is not available
+        /// * NFT is not the lead NFT of a lot
+        pub fn remove_lot(&mut self, id: NonFungibleLocalId) {
+            assert!(
+                self.available_nfts_list.contains(&id),
+                "NFT is not available!"
+            );
+
+            let lot = self.lots.remove(&id).expect("NFT is not the lead NFT of a lot!");
+            self.available_nfts_list
+                .extend(lot.into_iter().filter(|nft| *nft != id));
+        }
+
         /// Deposits an XRD domain into the vault
         /// 
         /// # Input
@@ -530,19 +2444,207 @@ mod piers_rugyard {
 
         //------ Getters ------//
         /// TODO - Add more getters
-        /// Returns the current auction or None
-        pub fn get_current_auction(&mut self) -> Option<Auction> {
-            self.current_auction.clone()
+        /// Returns an active auction or None
+        pub fn get_current_auction(&mut self, auction_id: u64) -> Option<Auction> {
+            let current_timestamp = Clock::current_time(TimePrecisionV2::Second);
+
+            self.active_auctions.get(&auction_id).map(|auction| {
+                let mut auction = auction.clone();
+                auction.status = auction.status_at(current_timestamp);
+                auction
+            })
+        }
+
+        /// Returns the earliest time the next auction can start, or None if it can start right away
+        pub fn get_next_start_timestamp(&mut self) -> Option<Instant> {
+            self.next_start_timestamp()
         }
 
-        /// Gets a completed auction by its id
-        pub fn get_completed_auction(&mut self, id: u64) -> Auction {
-            self.completed_auctions.get(&id).unwrap().clone()
+        /// Returns when the component was paused, or None if it's not paused
+        pub fn get_paused_at(&mut self) -> Option<Instant> {
+            self.paused_at
+        }
+
+        /// Returns all active auctions
+        pub fn get_active_auctions(&mut self) -> Vec<Auction> {
+            let current_timestamp = Clock::current_time(TimePrecisionV2::Second);
+
+            self.active_auction_ids
+                .iter()
+                .map(|id| {
+                    let mut auction = self.active_auctions.get(id).unwrap().clone();
+                    auction.status = auction.status_at(current_timestamp);
+                    auction
+                })
+                .collect()
+        }
+
+        /// Gets a completed auction by its id, or None if there is no completed auction with that id
+        pub fn get_completed_auction(&mut self, id: u64) -> Option<Auction> {
+            self.completed_auctions.get(&id).map(|auction| auction.clone())
+        }
+
+        /// Returns the completed auctions with an id from `from_id` up to `from_id + limit`.
+        /// Auctions that are still active are skipped.
+        ///
+        /// # Panics
+        /// * The limit is higher than 100
+        pub fn get_completed_auctions(&mut self, from_id: u64, limit: u64) -> Vec<Auction> {
+            assert!(limit <= 100, "Limit can't be higher than 100!");
+
+            (from_id..from_id.saturating_add(limit).min(self.next_auction_id))
+                .filter_map(|id| self.completed_auctions.get(&id).map(|auction| auction.clone()))
+                .collect()
+        }
+
+        /// Returns up to `limit` completed auctions of an NFT, starting at the `from`th auction
+        /// of the NFT
+        ///
+        /// # Panics
+        /// * The limit is higher than 100
+        pub fn get_auctions_by_nft(&mut self, id: NonFungibleLocalId, from: u64, limit: u64) -> Vec<Auction> {
+            assert!(limit <= 100, "Limit can't be higher than 100!");
+
+            let count = self.nft_auction_counts.get(&id).map_or(0, |count| *count);
+
+            (from..from.saturating_add(limit).min(count))
+                .map(|index| {
+                    let auction_id = *self.nft_auction_ids.get(&(id.clone(), index)).unwrap();
+                    self.completed_auctions.get(&auction_id).unwrap().clone()
+                })
+                .collect()
+        }
+
+        /// Returns up to `limit` auctions won by an account, starting at the `from`th auction
+        /// it won
+        ///
+        /// # Panics
+        /// * The limit is higher than 100
+        pub fn get_auctions_by_winner(&mut self, account: ComponentAddress, from: u64, limit: u64) -> Vec<Auction> {
+            assert!(limit <= 100, "Limit can't be higher than 100!");
+
+            let count = self.winner_auction_counts.get(&account).map_or(0, |count| *count);
+
+            (from..from.saturating_add(limit).min(count))
+                .map(|index| {
+                    let auction_id = *self.winner_auction_ids.get(&(account, index)).unwrap();
+                    self.completed_auctions.get(&auction_id).unwrap().clone()
+                })
+                .collect()
+        }
+
+        /// Returns the number of auctions that have been started
+        pub fn get_auction_count(&mut self) -> u64 {
+            self.next_auction_id - 1
+        }
+
+        /// Returns the status of an auction. Active auctions report their current status and
+        /// completed auctions the status they ended with. The next auction is Scheduled while
+        /// there is an NFT to auction and it has to wait for its scheduled start.
+        ///
+        /// # Input
+        /// * `auction_id`: a u64 with the id of the auction
+        ///
+        /// # Output
+        /// * An Option<AuctionStatus> with the status, or None if the auction is unknown or the
+        ///   next auction isn't waiting for a scheduled start
+        pub fn get_auction_status(&mut self, auction_id: u64) -> Option<AuctionStatus> {
+            let current_timestamp = Clock::current_time(TimePrecisionV2::Second);
+
+            if let Some(auction) = self.active_auctions.get(&auction_id) {
+                return Some(auction.status_at(current_timestamp));
+            }
+
+            if let Some(auction) = self.completed_auctions.get(&auction_id) {
+                return Some(auction.status.clone());
+            }
+
+            let is_waiting = self
+                .next_start_timestamp()
+                .is_some_and(|timestamp| current_timestamp.compare(timestamp, TimeComparisonOperator::Lt));
+
+            (auction_id == self.next_auction_id
+                && self.active
+                && !self.available_nfts_list.is_empty()
+                && is_waiting)
+                .then_some(AuctionStatus::Scheduled)
+        }
+
+        /// Returns up to `limit` bids of an auction, starting at bid index `from`
+        ///
+        /// # Panics
+        /// * The limit is higher than 100
+        pub fn get_bids(&mut self, auction_id: u64, from: u64, limit: u64) -> Vec<Bid> {
+            assert!(limit <= 100, "Limit can't be higher than 100!");
+
+            (from..from.saturating_add(limit))
+                .map_while(|index| self.bids.get(&(auction_id, index)).map(|bid| bid.clone()))
+                .collect()
         }
 
         /// Get the amount of profit
         pub fn get_profit_amount(&mut self) -> Decimal {
             self.early_vault.amount()
         }
+
+        /// Returns the interface and address of the pool used for swapping
+        pub fn get_swap_pool(&mut self) -> (SwapAdapter, ComponentAddress) {
+            (self.swap_adapter.clone(), self.early_pool)
+        }
+
+        /// Returns the share of purchased EARLY that is burned, if any
+        pub fn get_early_burn(&mut self) -> Option<EarlyBurn> {
+            self.early_burn.clone()
+        }
+
+        /// Get the total amount of EARLY that was burned
+        pub fn get_total_early_burned(&mut self) -> Decimal {
+            self.total_early_burned
+        }
+
+        /// Returns the share of purchased EARLY that is paid out as dividends
+        pub fn get_dividend_percentage(&mut self) -> Decimal {
+            self.dividend_percentage
+        }
+
+        /// Returns the unclaimed EARLY dividends of a set of NFTs
+        pub fn get_claimable_dividends(&mut self, ids: Vec<NonFungibleLocalId>) -> Decimal {
+            self.claimable_dividends(&ids)
+        }
+
+        /// Returns how the proceeds of an auction are split
+        pub fn get_profit_split(&mut self) -> Vec<ProfitShare> {
+            self.profit_split.clone()
+        }
+
+        /// Get the amount of profit that was kept as XRD
+        pub fn get_xrd_profit_amount(&mut self) -> Decimal {
+            self.xrd_profits_vault.amount()
+        }
+
+        /// Returns the deferred swap configuration, or None if proceeds are swapped immediately
+        pub fn get_deferred_swaps(&mut self) -> Option<DeferredSwaps> {
+            self.deferred_swaps.clone()
+        }
+
+        /// Returns the protection for the EARLY swap, if any
+        pub fn get_swap_protection(&mut self) -> Option<SwapProtection> {
+            self.swap_protection.clone()
+        }
+
+        /// Get the amount of XRD waiting in the XRD treasury to be swapped
+        pub fn get_treasury_amount(&mut self) -> Decimal {
+            self.xrd_treasury_vault.amount()
+        }
+
+        /// Get the amount of XRD in the XRD treasury that is used to buy EARLY to burn
+        pub fn get_pending_burn_amount(&mut self) -> Decimal {
+            self.pending_burn_xrd
+        }
+
+        /// Returns the reward configuration for settling an auction
+        pub fn get_settler_reward(&mut self) -> SettlerReward {
+            self.settler_reward.clone()
+        }
     }
 }
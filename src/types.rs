@@ -5,11 +5,246 @@ pub struct Auction {
     pub id: u64,
     pub start_timestamp: Instant,
     pub end_timestamp: Instant,
-    pub nft: NonFungibleLocalId,
+    pub nfts: Vec<NonFungibleLocalId>,
     pub highest_bid: Option<Decimal>,
     pub highest_bidder: Option<Global<Account>>,
     pub bid_count: u64,
-    pub latest_bids: Vec<Bid>
+    pub latest_bids: Vec<Bid>,
+    pub reserve_price: Decimal,
+    pub starting_price: Decimal,
+    pub buffer_minutes: u64,
+    pub minimum_bid_increase: Decimal,
+    pub minimum_bid_increase_percentage: Decimal,
+    pub auction_type: AuctionType,
+    pub commit_end_timestamp: Option<Instant>,
+    pub buy_now_price: Option<Decimal>,
+    pub scheduled_start_timestamp: Option<Instant>,
+    pub extension_count: u64,
+    pub max_extensions: Option<u64>,
+    pub hard_end_timestamp: Option<Instant>,
+    pub status: AuctionStatus,
+    pub settled_timestamp: Option<Instant>,
+    pub settler: Option<Global<Account>>,
+    pub settler_reward: Decimal,
+    /// All EARLY bought with the proceeds while settling, including EARLY that was burned or
+    /// paid out as dividends. 0 if the swap was deferred or skipped.
+    pub early_received: Decimal,
+    pub final_price: Option<Decimal>,
+}
+
+impl Auction {
+    /// Adds a bid to the bid history. Keeps the history to 10 pieces as to not brick the component.
+    pub fn add_bid(&mut self, bid: Bid) {
+        self.bid_count += 1;
+        if self.latest_bids.len() == 10 {
+            self.latest_bids.remove(0);
+        }
+        self.latest_bids.push(bid);
+    }
+
+    /// Returns the minimum increase over a bid of `amount`: the absolute minimum bid increase
+    /// or the percentage of `amount`, whichever is higher
+    pub fn minimum_increase_over(&self, amount: Decimal) -> Decimal {
+        let percentage_increase = amount
+            .checked_mul(self.minimum_bid_increase_percentage)
+            .expect("Couldn't calculate minimum bid increase!");
+
+        self.minimum_bid_increase.max(percentage_increase)
+    }
+
+    /// Returns the lowest amount the next bid must be
+    pub fn minimum_next_bid(&self) -> Decimal {
+        match self.highest_bid {
+            Some(highest_bid) => highest_bid + self.minimum_increase_over(highest_bid),
+            None => self.starting_price,
+        }
+    }
+
+    /// Extends the auction if `timestamp` falls within `buffer_minutes` of the end of the auction,
+    /// as long as the maximum number of extensions is not reached. The auction is never extended
+    /// past the hard end. Returns whether the auction was extended.
+    pub fn extend(&mut self, timestamp: Instant, buffer_minutes: u64) -> bool {
+        let mut timestamp_plus_buffer = timestamp
+            .add_minutes(buffer_minutes as i64)
+            .expect("Could not add minutes!");
+        if let Some(hard_end_timestamp) = self.hard_end_timestamp {
+            timestamp_plus_buffer = timestamp_plus_buffer.min(hard_end_timestamp);
+        }
+
+        let extensions_left = self
+            .max_extensions
+            .map_or(true, |max_extensions| self.extension_count < max_extensions);
+
+        if extensions_left
+            && timestamp_plus_buffer > self.end_timestamp
+            && timestamp < self.end_timestamp
+        {
+            self.end_timestamp = timestamp_plus_buffer;
+            self.extension_count += 1;
+            self.status = AuctionStatus::Extended;
+            return true;
+        }
+
+        false
+    }
+
+    /// Returns the status of the auction at `timestamp`. A live or extended auction has
+    /// ended after its end, until it's settled.
+    pub fn status_at(&self, timestamp: Instant) -> AuctionStatus {
+        match self.status {
+            AuctionStatus::Live | AuctionStatus::Extended if timestamp >= self.end_timestamp => {
+                AuctionStatus::Ended
+            }
+            _ => self.status.clone(),
+        }
+    }
+}
+
+/// Key of a bid in the full bid history: the auction id and the index of the bid
+pub type BidKey = (u64, u64);
+
+/// Key of an auction in the lookup by NFT: the NFT and the index of the auction for that NFT
+pub type NftAuctionKey = (NonFungibleLocalId, u64);
+
+/// Key of an auction in the lookup by winner: the account and the index of the auction it won
+pub type WinnerAuctionKey = (ComponentAddress, u64);
+
+#[derive(ScryptoSbor, PartialEq, Debug, Clone)]
+pub enum AuctionStatus {
+    /// The next auction is waiting for its scheduled start
+    Scheduled,
+    Live,
+    Extended,
+    Ended,
+    Settled,
+    Unsold,
+    Cancelled,
+}
+
+/// Where the NFTs of a cancelled auction go
+#[derive(ScryptoSbor, PartialEq, Debug, Clone)]
+pub enum NftPlacement {
+    /// Back to the front of the queue
+    Front,
+    /// Back to the end of the queue
+    Back,
+    /// Burned
+    Burn,
+}
+
+/// Overrides of the component wide auction settings for a specific NFT
+#[derive(ScryptoSbor, PartialEq, Debug, Clone, Default)]
+pub struct AuctionConfig {
+    pub duration_minutes: Option<u64>,
+    pub buffer_minutes: Option<u64>,
+    pub minimum_bid_increase: Option<Decimal>,
+    pub reserve_price: Option<Decimal>,
+    pub starting_price: Option<Decimal>,
+    pub buy_now_price: Option<Decimal>,
+}
+
+impl AuctionConfig {
+    /// Panics if one of the overrides is invalid. The duration and buffer are checked against
+    /// each other using the default for the one that is not overridden.
+    pub fn validate(&self, default_duration_minutes: u64, default_buffer_minutes: u64) {
+        assert!(
+            self.duration_minutes.map_or(true, |minutes| minutes > 0),
+            "Duration must be longer than 0 minutes!"
+        );
+        assert!(
+            self.buffer_minutes.unwrap_or(default_buffer_minutes)
+                < self.duration_minutes.unwrap_or(default_duration_minutes),
+            "Buffer must be shorter than the duration!"
+        );
+        assert!(
+            self.minimum_bid_increase.map_or(true, |increase| increase > dec!(0)),
+            "Minimum bid increase must be higher than 0!"
+        );
+        assert!(
+            self.reserve_price.map_or(true, |price| price >= dec!(0)),
+            "Reserve price can't be lower than 0!"
+        );
+        assert!(
+            self.starting_price.map_or(true, |price| price > dec!(0)),
+            "Starting price must be higher than 0!"
+        );
+        assert!(
+            self.buy_now_price.map_or(true, |price| price > dec!(0)),
+            "Buy now price must be higher than 0!"
+        );
+        if let Some(buy_now_price) = self.buy_now_price {
+            assert!(
+                self.reserve_price.map_or(true, |price| buy_now_price >= price),
+                "Buy now price can't be lower than the reserve price!"
+            );
+            assert!(
+                self.starting_price.map_or(true, |price| buy_now_price >= price),
+                "Buy now price can't be lower than the starting price!"
+            );
+        }
+    }
+}
+
+#[derive(ScryptoSbor, PartialEq, Debug, Clone)]
+pub enum AuctionType {
+    English,
+    Dutch(DutchAuction),
+    SealedBid(SealedBidAuction),
+}
+
+#[derive(ScryptoSbor, PartialEq, Debug, Clone)]
+pub struct DutchAuction {
+    pub start_price: Decimal,
+    pub floor_price: Decimal,
+    pub decay: PriceDecay,
+}
+
+impl DutchAuction {
+    /// Calculates the price at `timestamp` for an auction running from `start_timestamp` to
+    /// `end_timestamp`. The price never drops below the floor price.
+    pub fn price_at(
+        &self,
+        start_timestamp: Instant,
+        end_timestamp: Instant,
+        timestamp: Instant,
+    ) -> Decimal {
+        let duration = end_timestamp.seconds_since_unix_epoch - start_timestamp.seconds_since_unix_epoch;
+        let elapsed = (timestamp.seconds_since_unix_epoch - start_timestamp.seconds_since_unix_epoch)
+            .clamp(0, duration);
+        let price_range = self.start_price - self.floor_price;
+
+        if elapsed >= duration {
+            return self.floor_price;
+        }
+
+        match &self.decay {
+            PriceDecay::Linear => {
+                self.start_price - price_range * Decimal::from(elapsed) / Decimal::from(duration)
+            }
+            PriceDecay::Stepped { step_minutes } => {
+                let step_seconds = *step_minutes as i64 * 60;
+                let stepped_elapsed = elapsed - elapsed % step_seconds;
+                self.start_price
+                    - price_range * Decimal::from(stepped_elapsed) / Decimal::from(duration)
+            }
+            PriceDecay::Exponential { decay_per_minute } => {
+                let remaining = (dec!(1) - *decay_per_minute)
+                    .checked_powi(elapsed / 60)
+                    .expect("Could not calculate price decay");
+                self.floor_price + price_range * remaining
+            }
+        }
+    }
+}
+
+#[derive(ScryptoSbor, PartialEq, Debug, Clone)]
+pub enum PriceDecay {
+    /// The price drops continuously from the start price to the floor price
+    Linear,
+    /// The price drops linearly, but only once every `step_minutes`
+    Stepped { step_minutes: u64 },
+    /// Every minute the price drops by `decay_per_minute` of its distance to the floor price
+    Exponential { decay_per_minute: Decimal },
 }
 
 #[derive(ScryptoSbor, PartialEq, Debug, Clone)]
@@ -17,7 +252,8 @@ pub struct Bid {
     pub amount: Decimal,
     pub bidder: Global<Account>,
     pub timestamp: Instant,
-    pub transaction_hash: Hash
+    pub transaction_hash: Hash,
+    pub is_proxy: bool,
 }
 
 #[derive(ScryptoSbor, NonFungibleData, Debug, PartialEq, Clone)]
@@ -25,3 +261,249 @@ pub struct NFT {
     pub key_image_url: Url,
     pub name: String,
 }
+
+#[derive(ScryptoSbor, PartialEq, Debug, Clone)]
+pub struct SealedBidAuction {
+    pub pricing: SealedBidPricing,
+    pub reveal_duration_minutes: u64,
+}
+
+#[derive(ScryptoSbor, PartialEq, Debug, Clone)]
+pub enum SealedBidPricing {
+    /// The winner pays their own bid
+    FirstPrice,
+    /// The winner pays the second highest bid (Vickrey auction)
+    SecondPrice,
+}
+
+#[derive(ScryptoSbor, PartialEq, Debug, Clone)]
+pub struct SealedBid {
+    pub bidder: Global<Account>,
+    pub commitment: Hash,
+    pub deposit: Decimal,
+    pub revealed_amount: Option<Decimal>,
+}
+
+impl SealedBid {
+    /// Calculates the commitment for a sealed bid, which is the hash of `"<amount>:<salt>"`
+    pub fn commitment(amount: Decimal, salt: &str) -> Hash {
+        hash(format!("{}:{}", amount, salt))
+    }
+}
+
+#[derive(ScryptoSbor, PartialEq, Debug, Clone)]
+pub struct SettlerReward {
+    pub percentage: Decimal,
+    pub cap: Option<Decimal>,
+    pub floor: Option<Decimal>,
+}
+
+impl SettlerReward {
+    /// Calculates the reward for settling an auction with a winning bid of `amount`.
+    /// The reward never exceeds the winning bid itself.
+    pub fn reward_for(&self, amount: Decimal) -> Decimal {
+        let mut reward = amount
+            .checked_mul(self.percentage)
+            .expect("Couldn't calculate reward!");
+
+        if let Some(floor) = self.floor {
+            reward = reward.max(floor);
+        }
+        if let Some(cap) = self.cap {
+            reward = reward.min(cap);
+        }
+
+        reward.min(amount)
+    }
+}
+
+#[derive(ScryptoSbor, PartialEq, Debug, Clone)]
+pub struct SwapProtection {
+    /// The expected amount of EARLY per XRD
+    pub reference_price: Decimal,
+    /// The maximum slippage from the reference price, e.g. 0.05 for 5%
+    pub max_slippage: Decimal,
+}
+
+impl SwapProtection {
+    /// Calculates the minimum amount of EARLY the swap of `xrd_amount` must return
+    pub fn minimum_output(&self, xrd_amount: Decimal) -> Decimal {
+        xrd_amount
+            .checked_mul(self.reference_price)
+            .and_then(|amount| amount.checked_mul(dec!(1) - self.max_slippage))
+            .expect("Couldn't calculate minimum output!")
+    }
+}
+
+#[derive(ScryptoSbor, PartialEq, Debug, Clone)]
+pub struct DeferredSwaps {
+    /// The share of each swap paid to the caller of `execute_swap`, e.g. 0.01 for 1%
+    pub bounty_percentage: Decimal,
+    /// The maximum amount of XRD per swap
+    pub max_swap_amount: Option<Decimal>,
+}
+
+#[derive(ScryptoSbor, PartialEq, Debug, Clone)]
+pub struct ProfitShare {
+    pub destination: ProfitDestination,
+    /// The share of the proceeds, e.g. 0.25 for 25%
+    pub percentage: Decimal,
+}
+
+#[derive(ScryptoSbor, PartialEq, Debug, Clone)]
+pub enum ProfitDestination {
+    /// Kept as XRD in the component
+    KeepXrd,
+    /// Swapped to EARLY and kept in the component
+    SwapToEarly,
+    /// Sent to an account through the account locker
+    Account(Global<Account>),
+    /// Swapped to EARLY which is then burned
+    Burn,
+}
+
+#[derive(ScryptoSbor, PartialEq, Debug, Clone)]
+pub struct EarlyBurn {
+    /// The share of purchased EARLY that is burned, e.g. 0.1 for 10%
+    pub percentage: Decimal,
+    /// A component with a `deposit(Bucket)` method to send the EARLY to instead of burning it
+    pub burn_component: Option<ComponentAddress>,
+}
+
+/// The interface of the DEX pool used to swap XRD to EARLY
+#[derive(ScryptoSbor, PartialEq, Debug, Clone)]
+pub enum SwapAdapter {
+    /// `swap(Bucket) -> Bucket`
+    OciswapV1,
+    /// `swap(Bucket) -> (Bucket, Bucket)`, returning the output and the remainder
+    OciswapV2,
+    /// `swap(Bucket, ResourceAddress) -> (Bucket, Option<Bucket>)`, returning the output and the remainder
+    DefiPlaza,
+    /// `swap(Bucket) -> (Bucket, Bucket)`, returning the remainder and the output
+    CaviarNine,
+}
+
+impl SwapAdapter {
+    /// Swaps `input` for `output_address` on `pool`. Returns the output and the
+    /// remainder of the input, if any.
+    pub fn swap(&self, pool: ComponentAddress, input: Bucket, output_address: ResourceAddress) -> (Bucket, Option<Bucket>) {
+        let pool_component: Global<AnyComponent> = Global::from(pool);
+
+        match self {
+            SwapAdapter::OciswapV1 => {
+                let output = pool_component.call_raw::<Bucket>("swap", scrypto_args!(input));
+                (output, None)
+            }
+            SwapAdapter::OciswapV2 => {
+                let (output, remainder) =
+                    pool_component.call_raw::<(Bucket, Bucket)>("swap", scrypto_args!(input));
+                (output, Some(remainder))
+            }
+            SwapAdapter::DefiPlaza => pool_component.call_raw::<(Bucket, Option<Bucket>)>(
+                "swap",
+                scrypto_args!(input, output_address),
+            ),
+            SwapAdapter::CaviarNine => {
+                let (remainder, output) =
+                    pool_component.call_raw::<(Bucket, Bucket)>("swap", scrypto_args!(input));
+                (output, Some(remainder))
+            }
+        }
+    }
+
+    /// Returns the amount of `output_address` the swap of `input_amount` of `input_address`
+    /// on `pool` would return
+    pub fn quote(
+        &self,
+        pool: ComponentAddress,
+        input_address: ResourceAddress,
+        input_amount: Decimal,
+        output_address: ResourceAddress,
+    ) -> Decimal {
+        let pool_component: Global<AnyComponent> = Global::from(pool);
+
+        match self {
+            SwapAdapter::OciswapV1 => pool_component.call_raw::<Decimal>(
+                "get_output_amount",
+                scrypto_args!(input_address, input_amount),
+            ),
+            SwapAdapter::OciswapV2 => pool_component.call_raw::<Decimal>(
+                "preview_swap",
+                scrypto_args!(input_address, input_amount),
+            ),
+            SwapAdapter::DefiPlaza => pool_component.call_raw::<Decimal>(
+                "quote",
+                scrypto_args!(input_address, input_amount, output_address),
+            ),
+            SwapAdapter::CaviarNine => pool_component.call_raw::<Decimal>(
+                "get_amount_out",
+                scrypto_args!(input_address, input_amount),
+            ),
+        }
+    }
+}
+
+#[derive(ScryptoSbor, PartialEq, Debug, Clone)]
+pub struct ExtensionLimits {
+    /// The maximum number of times bids can extend an auction
+    pub max_extensions: Option<u64>,
+    /// The maximum total time in minutes bids can extend an auction
+    pub max_extension_minutes: Option<u64>,
+}
+
+#[derive(ScryptoSbor, PartialEq, Debug, Clone)]
+pub struct AuctionSchedule {
+    /// The time of day auctions start, in minutes after midnight UTC
+    pub daily_start_minutes: Option<u64>,
+    /// The minimum time between the end of an auction and the start of the next one
+    pub cooldown_minutes: Option<u64>,
+}
+
+impl AuctionSchedule {
+    /// Calculates the earliest time the next auction can start after an auction ended at `timestamp`
+    pub fn next_start(&self, timestamp: Instant) -> Instant {
+        let earliest_start = timestamp
+            .add_minutes(self.cooldown_minutes.unwrap_or(0) as i64)
+            .expect("Could not add minutes!");
+
+        match self.daily_start_minutes {
+            Some(daily_start_minutes) => {
+                let seconds = earliest_start.seconds_since_unix_epoch;
+                let day_start = seconds - seconds.rem_euclid(24 * 60 * 60);
+                let mut next_start = day_start + daily_start_minutes as i64 * 60;
+                if next_start < seconds {
+                    next_start += 24 * 60 * 60;
+                }
+
+                Instant::new(next_start)
+            }
+            None => earliest_start,
+        }
+    }
+
+    /// Calculates the earliest time the first auction can start when the schedule is set at
+    /// `timestamp`. Only the daily start time applies, as there is no auction to cool down from.
+    pub fn first_start(&self, timestamp: Instant) -> Option<Instant> {
+        self.daily_start_minutes.map(|_| {
+            AuctionSchedule {
+                daily_start_minutes: self.daily_start_minutes,
+                cooldown_minutes: None,
+            }
+            .next_start(timestamp)
+        })
+    }
+}
+
+/// A configuration change, emitted with `PiersRugyardConfigUpdated`
+#[derive(ScryptoSbor, PartialEq, Debug, Clone)]
+pub enum ConfigUpdate {
+    SettlerReward(SettlerReward),
+    SwapProtection(Option<SwapProtection>),
+    SwapPool(SwapAdapter, ComponentAddress),
+    DeferredSwaps(Option<DeferredSwaps>),
+    ProfitSplit(Vec<ProfitShare>),
+    EarlyBurn(Option<EarlyBurn>),
+    DividendPercentage(Decimal),
+    ExtensionLimits(ExtensionLimits),
+    AuctionSchedule(AuctionSchedule),
+}
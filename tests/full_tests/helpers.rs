@@ -147,7 +147,10 @@ pub fn create_prepared_test_environment() -> (
     // NFTs
     let mut nfts = vec![];
     for i in 0..80 {
-        nfts.push(("https://www.google.com", format!("My NFT {i}")));
+        nfts.push((
+            ("https://www.google.com", format!("My NFT {i}")),
+            None::<AuctionConfig>,
+        ));
     }
 
     // Mint NFTs and start auction
@@ -201,15 +204,16 @@ pub fn get_account_balance(
     balance
 }
 
-// Gets the currently active auction
+// Gets an active auction
 pub fn get_current_auction(
     ledger: &mut DefaultLedgerSimulator,
     component: ComponentAddress,
+    auction_id: u64,
     account: &Account,
 ) -> Option<Auction> {
     let manifest = ManifestBuilder::new()
         .lock_fee_from_faucet()
-        .call_method(component, "get_current_auction", manifest_args!())
+        .call_method(component, "get_current_auction", manifest_args!(auction_id))
         .build();
 
     let receipt = ledger.execute_manifest(
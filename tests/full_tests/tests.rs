@@ -22,7 +22,7 @@ fn perform_complete_auction() {
         .withdraw_from_account(account1.address, XRD, dec!(50))
         .take_all_from_worktop(XRD, "xrd_bucket")
         .call_method_with_name_lookup(component, "bid", |lookup| {
-            (lookup.bucket("xrd_bucket"), account1.address)
+            (1u64, lookup.bucket("xrd_bucket"), account1.address)
         })
         .build();
 
@@ -41,7 +41,7 @@ fn perform_complete_auction() {
         .withdraw_from_account(account2.address, XRD, dec!(100))
         .take_all_from_worktop(XRD, "xrd_bucket")
         .call_method_with_name_lookup(component, "bid", |lookup| {
-            (lookup.bucket("xrd_bucket"), account2.address)
+            (1u64, lookup.bucket("xrd_bucket"), account2.address)
         })
         .build();
 
@@ -67,7 +67,7 @@ fn perform_complete_auction() {
         .withdraw_from_account(account3.address, XRD, dec!(150))
         .take_all_from_worktop(XRD, "xrd_bucket")
         .call_method_with_name_lookup(component, "bid", |lookup| {
-            (lookup.bucket("xrd_bucket"), account3.address)
+            (1u64, lookup.bucket("xrd_bucket"), account3.address)
         })
         .build();
 
@@ -86,7 +86,7 @@ fn perform_complete_auction() {
     );
 
     // Forward time to end the auction
-    let auction: Auction = get_current_auction(&mut ledger, component, &account1).unwrap();
+    let auction: Auction = get_current_auction(&mut ledger, component, 1, &account1).unwrap();
     change_time(&mut ledger, auction.end_timestamp);
 
     // Settle auction with account 1
@@ -97,7 +97,7 @@ fn perform_complete_auction() {
         .call_method(
             component,
             "settle_auction",
-            manifest_args!(account1.address),
+            manifest_args!(1u64, account1.address),
         )
         .deposit_batch(account1.address, ManifestExpression::EntireWorktop)
         .build();
@@ -175,12 +175,12 @@ fn perform_massive_auction_cost_test() {
 
     // Place bids with account 2
     get_shitton_of_xrd(&mut ledger, &account2);
-    for _ in 0..80 {
-        complete_auction_process(&mut ledger, &account2, component);
+    for i in 0..80 {
+        complete_auction_process(&mut ledger, &account2, component, i + 1);
     }
 }
 
-fn complete_auction_process(ledger: &mut DefaultLedgerSimulator, account: &crate::full_tests::helpers::Account, component: ComponentAddress) {
+fn complete_auction_process(ledger: &mut DefaultLedgerSimulator, account: &crate::full_tests::helpers::Account, component: ComponentAddress, auction_id: u64) {
     for i in 1..10 {
         let bid_amount = i * 50;
         let manifest = ManifestBuilder::new()
@@ -188,7 +188,7 @@ fn complete_auction_process(ledger: &mut DefaultLedgerSimulator, account: &crate
             .withdraw_from_account(account.address, XRD, Decimal::from(bid_amount))
             .take_all_from_worktop(XRD, "xrd_bucket")
             .call_method_with_name_lookup(component, "bid", |lookup| {
-                (lookup.bucket("xrd_bucket"), account.address)
+                (auction_id, lookup.bucket("xrd_bucket"), account.address)
             })
             .build();
 
@@ -199,11 +199,11 @@ fn complete_auction_process(ledger: &mut DefaultLedgerSimulator, account: &crate
 
         let cost = receipt.fee_summary.total_cost();
         println!("Bid {} cost {}", i, cost);    
-        let auction: Auction = get_current_auction(ledger, component, account).unwrap();
+        let auction: Auction = get_current_auction(ledger, component, auction_id, account).unwrap();
     }
 
     // Forward time to end the auction
-    let auction: Auction = get_current_auction(ledger, component, account).unwrap();
+    let auction: Auction = get_current_auction(ledger, component, auction_id, account).unwrap();
     println!("Bid count: {}", auction.bid_count);
     change_time(ledger, auction.end_timestamp);
 
@@ -213,7 +213,7 @@ fn complete_auction_process(ledger: &mut DefaultLedgerSimulator, account: &crate
         .call_method(
             component,
             "settle_auction",
-            manifest_args!(account.address),
+            manifest_args!(auction_id, account.address),
         )
         .deposit_batch(account.address, ManifestExpression::EntireWorktop)
         .build();
@@ -1,4 +1,8 @@
+use mock_oci_pool::black_hole::mockblackhole_test::*;
+use mock_oci_pool::caviarnine::mockcaviarninepool_test::*;
+use mock_oci_pool::defiplaza::mockdefiplazapool_test::*;
 use mock_oci_pool::mockocipool_test::*;
+use mock_oci_pool::ociswap_v2::mockociswapv2pool_test::*;
 use piers_rugyard::piers_rugyard_test::*;
 use piers_rugyard::types::*;
 use scrypto::prelude::Url;
@@ -120,16 +124,161 @@ pub fn instantiate_oci_pool(
     Ok((oci_pool, oci_pool_address, early_resource_address))
 }
 
+// Instantiates a mock pool with the interface of the adapter for an existing EARLY resource
+pub fn instantiate_mock_pool(
+    env: &mut TestEnvironment<InMemorySubstateDatabase>,
+    adapter: SwapAdapter,
+    early_resource_address: ResourceAddress,
+) -> Result<ComponentAddress, RuntimeError> {
+    instantiate_mock_pool_with_reserves(
+        env,
+        adapter,
+        early_resource_address,
+        dec!(100_000_000),
+        dec!(100_000_000),
+    )
+}
+
+// Instantiates a mock pool with the interface of the adapter and the given reserves
+pub fn instantiate_mock_pool_with_reserves(
+    env: &mut TestEnvironment<InMemorySubstateDatabase>,
+    adapter: SwapAdapter,
+    early_resource_address: ResourceAddress,
+    xrd_reserve: Decimal,
+    early_reserve: Decimal,
+) -> Result<ComponentAddress, RuntimeError> {
+    let early_bucket = BucketFactory::create_fungible_bucket(
+        early_resource_address,
+        early_reserve,
+        CreationStrategy::Mock,
+        env,
+    )?;
+    let xrd_bucket =
+        BucketFactory::create_fungible_bucket(XRD, xrd_reserve, CreationStrategy::Mock, env)?;
+
+    let mock_oci_pool_package_address =
+        PackageFactory::compile_and_publish("mock_oci_pool", env, CompileProfile::Fast)?;
+    let pool_address = match adapter {
+        SwapAdapter::OciswapV1 => {
+            MockOciPool::instantiate(
+                xrd_bucket.into(),
+                early_bucket.into(),
+                mock_oci_pool_package_address,
+                env,
+            )?
+            .1
+        }
+        SwapAdapter::OciswapV2 => {
+            MockOciswapV2Pool::instantiate(
+                xrd_bucket.into(),
+                early_bucket.into(),
+                mock_oci_pool_package_address,
+                env,
+            )?
+            .1
+        }
+        SwapAdapter::DefiPlaza => {
+            MockDefiPlazaPool::instantiate(
+                xrd_bucket.into(),
+                early_bucket.into(),
+                mock_oci_pool_package_address,
+                env,
+            )?
+            .1
+        }
+        SwapAdapter::CaviarNine => {
+            MockCaviarNinePool::instantiate(
+                xrd_bucket.into(),
+                early_bucket.into(),
+                mock_oci_pool_package_address,
+                env,
+            )?
+            .1
+        }
+    };
+
+    Ok(pool_address)
+}
+
+// Instantiates a mock black hole component to send tokens to
+pub fn instantiate_black_hole(
+    env: &mut TestEnvironment<InMemorySubstateDatabase>,
+) -> Result<ComponentAddress, RuntimeError> {
+    let mock_oci_pool_package_address =
+        PackageFactory::compile_and_publish("mock_oci_pool", env, CompileProfile::Fast)?;
+    let (_black_hole, black_hole_address) =
+        MockBlackHole::instantiate(mock_oci_pool_package_address, env)?;
+
+    Ok(black_hole_address)
+}
+
+/// Checks if an EARLY amount is within the price impact of the mock pools of the expected amount
+pub fn is_about_equal(amount: Decimal, expected: Decimal) -> bool {
+    let difference = if amount > expected {
+        amount - expected
+    } else {
+        expected - amount
+    };
+
+    difference <= expected * dec!(0.0001)
+}
+
 /// Helper function to mint an NFT
 pub fn mint_nfts(
     env: &mut TestEnvironment<InMemorySubstateDatabase>,
     mut component: PiersRugyard,
 ) -> Result<(), RuntimeError> {
     component.mint_nfts(
-        vec![NFT {
-            key_image_url: Url::of("https://www.google.com/"),
-            name: "My NFT!".to_string(),
-        }],
+        vec![(
+            NFT {
+                key_image_url: Url::of("https://www.google.com/"),
+                name: "My NFT!".to_string(),
+            },
+            None,
+        )],
+        env,
+    )?;
+
+    Ok(())
+}
+
+/// Helper function to mint an NFT with a buy now price
+pub fn mint_nfts_with_buy_now_price(
+    env: &mut TestEnvironment<InMemorySubstateDatabase>,
+    mut component: PiersRugyard,
+    buy_now_price: Decimal,
+) -> Result<(), RuntimeError> {
+    component.mint_nfts(
+        vec![(
+            NFT {
+                key_image_url: Url::of("https://www.google.com/"),
+                name: "My NFT!".to_string(),
+            },
+            Some(AuctionConfig {
+                buy_now_price: Some(buy_now_price),
+                ..Default::default()
+            }),
+        )],
+        env,
+    )?;
+
+    Ok(())
+}
+
+/// Helper function to mint an NFT with auction config overrides
+pub fn mint_nfts_with_auction_config(
+    env: &mut TestEnvironment<InMemorySubstateDatabase>,
+    mut component: PiersRugyard,
+    auction_config: AuctionConfig,
+) -> Result<(), RuntimeError> {
+    component.mint_nfts(
+        vec![(
+            NFT {
+                key_image_url: Url::of("https://www.google.com/"),
+                name: "My NFT!".to_string(),
+            },
+            Some(auction_config),
+        )],
         env,
     )?;
 
@@ -220,3 +369,82 @@ pub fn get_state_minimum_bid_increase(
 
     Ok(minimum_bid_increase)
 }
+
+pub fn get_state_reserve_price(
+    env: &mut TestEnvironment<InMemorySubstateDatabase>,
+    component: PiersRugyard,
+) -> Result<Decimal, RuntimeError> {
+    let reserve_price = env
+        .with_component_state::<PiersRugyardState, _, _, _>(component, |state, _env| {
+            state.reserve_price
+        })?;
+
+    Ok(reserve_price)
+}
+
+pub fn get_state_starting_price(
+    env: &mut TestEnvironment<InMemorySubstateDatabase>,
+    component: PiersRugyard,
+) -> Result<Decimal, RuntimeError> {
+    let starting_price = env
+        .with_component_state::<PiersRugyardState, _, _, _>(component, |state, _env| {
+            state.starting_price
+        })?;
+
+    Ok(starting_price)
+}
+
+pub fn get_state_early_address(
+    env: &mut TestEnvironment<InMemorySubstateDatabase>,
+    component: PiersRugyard,
+) -> Result<ResourceAddress, RuntimeError> {
+    let early_address = env
+        .with_component_state::<PiersRugyardState, _, _, _>(component, |state, _env| {
+            state.early_address
+        })?;
+
+    Ok(early_address)
+}
+
+pub fn get_locker_amount(
+    env: &mut TestEnvironment<InMemorySubstateDatabase>,
+    component: PiersRugyard,
+    account: Reference,
+    resource_address: ResourceAddress,
+) -> Result<Decimal, RuntimeError> {
+    let locker_address = env
+        .with_component_state::<PiersRugyardState, _, _, _>(component, |state, _env| {
+            state.locker.address()
+        })?;
+
+    env.call_method_typed::<_, _, Decimal>(
+        locker_address,
+        ACCOUNT_LOCKER_GET_AMOUNT_IDENT,
+        &(account, resource_address),
+    )
+}
+
+pub fn get_bid_vault_amount(
+    env: &mut TestEnvironment<InMemorySubstateDatabase>,
+    component: PiersRugyard,
+    auction_id: u64,
+) -> Result<Decimal, RuntimeError> {
+    env.with_component_state::<PiersRugyardState, _, _, _>(component, |state, env| {
+        let handle = env.key_value_store_open_entry(
+            &state.bid_vaults.id.0,
+            &scrypto_encode(&auction_id).unwrap(),
+            LockFlags::read_only(),
+        )?;
+        let vault = env.key_value_entry_get_typed::<Own>(handle)?;
+        env.key_value_entry_close(handle)?;
+
+        match vault {
+            Some(vault) => env.call_method_typed::<_, _, Decimal>(
+                vault.0,
+                VAULT_GET_AMOUNT_IDENT,
+                &VaultGetAmountInput {},
+            ),
+            None => Ok(dec!(0)),
+        }
+    })?
+}
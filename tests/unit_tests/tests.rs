@@ -163,7 +163,7 @@ fn cannot_delete_nft_without_owner() -> Result<(), RuntimeError> {
 }
 
 #[test]
-fn can_update_pool_address() -> Result<(), RuntimeError> {
+fn can_update_swap_pool() -> Result<(), RuntimeError> {
     // Arrange
     let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
 
@@ -175,7 +175,7 @@ fn can_update_pool_address() -> Result<(), RuntimeError> {
     let (_oci_pool, oci_pool_address, _early_resource_address) = instantiate_oci_pool(&mut env)?;
 
     // Act
-    component.update_pool_address(oci_pool_address, &mut env)?;
+    component.update_swap_pool(SwapAdapter::OciswapV1, oci_pool_address, &mut env)?;
 
     // Assert
     let new_pool_address = get_state_pool_address(&mut env, component)?;
@@ -193,14 +193,14 @@ fn can_update_pool_address() -> Result<(), RuntimeError> {
 }
 
 #[test]
-fn cannot_update_pool_address_without_owner() -> Result<(), RuntimeError> {
+fn cannot_update_swap_pool_without_owner() -> Result<(), RuntimeError> {
     // Arrange
     let (mut env, mut component, _owner_resource) = create_prepared_test_environment()?;
 
     let (_oci_pool, oci_pool_address, _early_resource_address) = instantiate_oci_pool(&mut env)?;
 
     // Act
-    let result = component.update_pool_address(oci_pool_address, &mut env);
+    let result = component.update_swap_pool(SwapAdapter::OciswapV1, oci_pool_address, &mut env);
 
     // Assert
     assert!(
@@ -446,10 +446,10 @@ fn can_settle_auction_after_auction_ended() -> Result<(), RuntimeError> {
     LocalAuthZone::push(account_proof, &mut env)?;
 
     component.start_new_auction(&mut env)?;
-    component.bid(xrd_bucket.into(), account, &mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
 
     let current_auction = component
-        .get_current_auction(&mut env)
+        .get_current_auction(1, &mut env)
         .expect("Couldn't get active auction")
         .expect("No active auction");
     env.set_current_time(
@@ -460,7 +460,7 @@ fn can_settle_auction_after_auction_ended() -> Result<(), RuntimeError> {
     );
 
     // Act
-    let result = component.settle_auction(account, &mut env);
+    let result = component.settle_auction(1, account, &mut env);
 
     // Assert
     assert!(result.is_ok(), "Could not settle auction!");
@@ -484,10 +484,10 @@ fn can_settle_auction_after_auction_ended_with_no_new_nfts() -> Result<(), Runti
     LocalAuthZone::push(account_proof, &mut env)?;
 
     component.start_new_auction(&mut env)?;
-    component.bid(xrd_bucket.into(), account, &mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
 
     let current_auction = component
-        .get_current_auction(&mut env)
+        .get_current_auction(1, &mut env)
         .expect("Couldn't get active auction")
         .expect("No active auction");
 
@@ -499,7 +499,7 @@ fn can_settle_auction_after_auction_ended_with_no_new_nfts() -> Result<(), Runti
     );
 
     // Act
-    let result = component.settle_auction(account, &mut env);
+    let result = component.settle_auction(1, account, &mut env);
 
     // Assert
     assert!(result.is_ok(), "Could not settle auction!");
@@ -519,10 +519,10 @@ fn cannot_settle_auction_before_auction_ended() -> Result<(), RuntimeError> {
     LocalAuthZone::push(account_proof, &mut env)?;
 
     component.start_new_auction(&mut env)?;
-    component.bid(xrd_bucket.into(), account, &mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
 
     let current_auction = component
-        .get_current_auction(&mut env)
+        .get_current_auction(1, &mut env)
         .expect("Couldn't get active auction")
         .expect("No active auction");
     env.set_current_time(
@@ -533,7 +533,7 @@ fn cannot_settle_auction_before_auction_ended() -> Result<(), RuntimeError> {
     );
 
     // Act
-    let result = component.settle_auction(account, &mut env);
+    let result = component.settle_auction(1, account, &mut env);
 
     // Assert
     assert!(result.is_err(), "Could settle auction before endtime!");
@@ -553,7 +553,7 @@ fn cannot_settle_auction_without_bids() -> Result<(), RuntimeError> {
     component.start_new_auction(&mut env)?;
 
     let current_auction = component
-        .get_current_auction(&mut env)
+        .get_current_auction(1, &mut env)
         .expect("Couldn't get active auction")
         .expect("No active auction");
     env.set_current_time(
@@ -564,7 +564,7 @@ fn cannot_settle_auction_without_bids() -> Result<(), RuntimeError> {
     );
 
     // Act
-    let result = component.settle_auction(account, &mut env);
+    let result = component.settle_auction(1, account, &mut env);
 
     // Assert
     assert!(result.is_err(), "Could settle auction without bids!");
@@ -587,10 +587,10 @@ fn can_withdraw_profits() -> Result<(), RuntimeError> {
     LocalAuthZone::push(account_proof, &mut env)?;
 
     component.start_new_auction(&mut env)?;
-    component.bid(xrd_bucket.into(), account, &mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
 
     let current_auction = component
-        .get_current_auction(&mut env)
+        .get_current_auction(1, &mut env)
         .expect("Couldn't get active auction")
         .expect("No active auction");
     env.set_current_time(
@@ -600,7 +600,7 @@ fn can_withdraw_profits() -> Result<(), RuntimeError> {
             .expect("Could not add time"),
     );
 
-    component.settle_auction(account, &mut env)?;
+    component.settle_auction(1, account, &mut env)?;
 
     // Act
     let profits = component.withdraw_profits(&mut env)?;
@@ -628,11 +628,11 @@ fn can_bid_active_auction() -> Result<(), RuntimeError> {
     component.start_new_auction(&mut env)?;
 
     // Act
-    let result = component.bid(xrd_bucket.into(), account, &mut env);
+    let result = component.bid(1, xrd_bucket.into(), account, &mut env);
 
     // Assert
     let current_auction = component
-        .get_current_auction(&mut env)
+        .get_current_auction(1, &mut env)
         .expect("Couldn't get active auction")
         .expect("No active auction");
 
@@ -667,7 +667,7 @@ fn cannot_bid_below_minimum_bid_amount() -> Result<(), RuntimeError> {
     component.start_new_auction(&mut env)?;
 
     // Act
-    let result = component.bid(xrd_bucket.into(), account, &mut env);
+    let result = component.bid(1, xrd_bucket.into(), account, &mut env);
 
     // Assert
     assert!(result.is_err(), "Could bid below minimum bid increase");
@@ -692,8 +692,8 @@ fn cannot_bid_same_as_highest_bid() -> Result<(), RuntimeError> {
     component.start_new_auction(&mut env)?;
 
     // Act
-    component.bid(xrd_bucket1.into(), account1, &mut env)?;
-    let result = component.bid(xrd_bucket2.into(), account2, &mut env);
+    component.bid(1, xrd_bucket1.into(), account1, &mut env)?;
+    let result = component.bid(1, xrd_bucket2.into(), account2, &mut env);
 
     // Assert
     assert!(result.is_err(), "Could bid the same as current highest bid");
@@ -719,8 +719,8 @@ fn cannot_bid_lower_than_highest_bid() -> Result<(), RuntimeError> {
     component.start_new_auction(&mut env)?;
 
     // Act
-    component.bid(xrd_bucket1.into(), account1, &mut env)?;
-    let result = component.bid(xrd_bucket2.into(), account2, &mut env);
+    component.bid(1, xrd_bucket1.into(), account1, &mut env)?;
+    let result = component.bid(1, xrd_bucket2.into(), account2, &mut env);
 
     // Assert
     assert!(result.is_err(), "Could bid below current highest bid");
@@ -742,18 +742,18 @@ fn can_bid_ended_auction_without_bids() -> Result<(), RuntimeError> {
     component.start_new_auction(&mut env)?;
 
     let current_auction = component
-        .get_current_auction(&mut env)
+        .get_current_auction(1, &mut env)
         .expect("Couldn't get active auction")
         .expect("No active auction");
 
     env.set_current_time(current_auction.end_timestamp.add_hours(1).unwrap()); // Move time past the end time
 
     // Act
-    let result = component.bid(xrd_bucket.into(), account, &mut env); // This should settle the first auction and start the second
+    let result = component.bid(1, xrd_bucket.into(), account, &mut env); // This should settle the first auction and start the second
 
     // Assert
     let current_auction: Auction = component
-        .get_current_auction(&mut env)
+        .get_current_auction(2, &mut env)
         .expect("Couldn't get active auction")
         .expect("No active auction");
 
@@ -786,13 +786,13 @@ fn cannot_bid_after_auction_end_with_existing_bids() -> Result<(), RuntimeError>
     component.start_new_auction(&mut env)?;
 
     // Act
-    component.bid(xrd_bucket1.into(), account1, &mut env)?; // Bid 1
+    component.bid(1, xrd_bucket1.into(), account1, &mut env)?; // Bid 1
     let current_auction = component
-        .get_current_auction(&mut env)
+        .get_current_auction(1, &mut env)
         .expect("Couldn't get active auction")
         .expect("No active auction");
     env.set_current_time(current_auction.end_timestamp.add_hours(1).unwrap()); // Move time past the end time
-    let result = component.bid(xrd_bucket2.into(), account2, &mut env); // Bid 2
+    let result = component.bid(1, xrd_bucket2.into(), account2, &mut env); // Bid 2
 
     // // Assert
     assert!(
@@ -817,7 +817,7 @@ fn active_auction_is_extended() -> Result<(), RuntimeError> {
     component.start_new_auction(&mut env)?;
 
     let current_auction: Auction = component
-        .get_current_auction(&mut env)
+        .get_current_auction(1, &mut env)
         .expect("Couldn't get active auction")
         .expect("No active auction");
     let time_buffer = get_state_auction_buffer(&mut env, component)?;
@@ -834,11 +834,11 @@ fn active_auction_is_extended() -> Result<(), RuntimeError> {
         .unwrap(); // This ends up being (previous endtime + 1 minute)
 
     // Act
-    component.bid(xrd_bucket.into(), account, &mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
 
     // Assert
     let current_auction: Auction = component
-        .get_current_auction(&mut env)
+        .get_current_auction(1, &mut env)
         .expect("Couldn't get active auction")
         .expect("No active auction");
 
@@ -849,3 +849,3267 @@ fn active_auction_is_extended() -> Result<(), RuntimeError> {
 
     Ok(())
 }
+
+#[test]
+fn can_update_reserve_price() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(proof, &mut env)?;
+
+    // Act
+    component.update_reserve_price(dec!(2000), &mut env)?;
+
+    // Assert
+    let reserve_price = get_state_reserve_price(&mut env, component)?;
+
+    assert!(
+        reserve_price == dec!(2000),
+        "Reserve price has not changed!"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_update_reserve_price_without_owner() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, _owner_resource) = create_prepared_test_environment()?;
+
+    // Act
+    let result = component.update_reserve_price(dec!(2000), &mut env);
+
+    // Assert
+    assert!(
+        result.is_err(),
+        "Reserve price was changed without an owner badge!"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn auction_below_reserve_price_is_unsold() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.set_nft_reserve_price(NonFungibleLocalId::integer(1), Some(dec!(2000)), &mut env)?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(
+        current_auction
+            .end_timestamp
+            .add_minutes(1)
+            .expect("Could not add time"),
+    );
+
+    // Act
+    let (reward, nft) = component.settle_auction(1, account, &mut env)?;
+
+    // Assert
+    assert!(
+        reward.amount(&mut env)? == dec!(0),
+        "Got a settlement reward for an unsold auction!"
+    );
+    assert!(nft.is_none(), "Got the NFT for an unsold auction!");
+
+    let available_nfts = get_state_available_nfts_list(&mut env, component)?;
+    assert!(
+        available_nfts.contains(&NonFungibleLocalId::integer(1)),
+        "Unsold NFT was not put back in the queue"
+    );
+
+    let current_auction = component
+        .get_current_auction(2, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    assert!(
+        current_auction.nfts == vec![NonFungibleLocalId::integer(2)],
+        "Next auction was not started"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn auction_uses_nft_auction_config() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.set_nft_auction_config(
+        NonFungibleLocalId::integer(1),
+        Some(AuctionConfig {
+            duration_minutes: Some(60),
+            buffer_minutes: Some(10),
+            minimum_bid_increase: Some(dec!(100)),
+            starting_price: Some(dec!(500)),
+            ..Default::default()
+        }),
+        &mut env,
+    )?;
+
+    // Act
+    component.start_new_auction(&mut env)?;
+
+    // Assert
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    assert!(
+        current_auction.end_timestamp
+            == current_auction
+                .start_timestamp
+                .add_minutes(60)
+                .expect("Could not add time"),
+        "Duration override was not applied"
+    );
+    assert!(
+        current_auction.buffer_minutes == 10,
+        "Buffer override was not applied"
+    );
+    assert!(
+        current_auction.minimum_bid_increase == dec!(100),
+        "Minimum bid increase override was not applied"
+    );
+    assert!(
+        current_auction.starting_price == dec!(500),
+        "Starting price override was not applied"
+    );
+    assert!(
+        current_auction.reserve_price == get_state_reserve_price(&mut env, component)?,
+        "Reserve price default was not used"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn minimum_bid_increase_update_keeps_nft_override() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.set_nft_auction_config(
+        NonFungibleLocalId::integer(1),
+        Some(AuctionConfig {
+            minimum_bid_increase: Some(dec!(100)),
+            ..Default::default()
+        }),
+        &mut env,
+    )?;
+    component.start_new_auction(&mut env)?;
+
+    // Act
+    component.update_minimum_bid_increase(dec!(10), &mut env)?;
+
+    // Assert
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    assert!(
+        current_auction.minimum_bid_increase == dec!(100),
+        "Minimum bid increase override was overwritten"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn auction_buffer_update_applies_to_active_auctions() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.set_nft_auction_config(
+        NonFungibleLocalId::integer(1),
+        Some(AuctionConfig {
+            buffer_minutes: Some(10),
+            ..Default::default()
+        }),
+        &mut env,
+    )?;
+    component.update_max_active_auctions(2, &mut env)?;
+    component.start_new_auction(&mut env)?;
+    component.start_new_auction(&mut env)?;
+
+    // Act
+    component.update_auction_buffer(20, &mut env)?;
+
+    // Assert
+    let auction_with_override = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    let auction_without_override = component
+        .get_current_auction(2, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    assert!(
+        auction_with_override.buffer_minutes == 10,
+        "Buffer override was overwritten"
+    );
+    assert!(
+        auction_without_override.buffer_minutes == 20,
+        "Active auction did not get the new buffer"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_update_auction_buffer_past_nft_duration_override() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.set_nft_auction_config(
+        NonFungibleLocalId::integer(1),
+        Some(AuctionConfig {
+            duration_minutes: Some(10),
+            ..Default::default()
+        }),
+        &mut env,
+    )?;
+
+    // Act
+    let result = component.update_auction_buffer(20, &mut env);
+
+    // Assert
+    assert!(
+        result.is_err(),
+        "Buffer was set above the duration override of an NFT"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_update_auction_duration_below_nft_buffer_override() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.set_nft_auction_config(
+        NonFungibleLocalId::integer(1),
+        Some(AuctionConfig {
+            buffer_minutes: Some(100),
+            ..Default::default()
+        }),
+        &mut env,
+    )?;
+
+    // Act
+    let result = component.update_auction_duration(50, &mut env);
+
+    // Assert
+    assert!(
+        result.is_err(),
+        "Duration was set below the buffer override of an NFT"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn auction_uses_auction_config_from_minting() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    mint_nfts_with_auction_config(
+        &mut env,
+        component,
+        AuctionConfig {
+            reserve_price: Some(dec!(1000)),
+            ..Default::default()
+        },
+    )?;
+    component.flip_status(&mut env)?;
+
+    // Act
+    component.start_new_auction(&mut env)?;
+
+    // Assert
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    assert!(
+        current_auction.reserve_price == dec!(1000),
+        "Reserve price override was not applied"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_set_invalid_nft_auction_config() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+
+    // Act
+    let result = component.set_nft_auction_config(
+        NonFungibleLocalId::integer(1),
+        Some(AuctionConfig {
+            duration_minutes: Some(10),
+            buffer_minutes: Some(10),
+            ..Default::default()
+        }),
+        &mut env,
+    );
+
+    // Assert
+    assert!(
+        result.is_err(),
+        "Auction config with a buffer as long as the duration was set!"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_set_nft_auction_buffer_longer_than_default_duration() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    let auction_duration = get_state_auction_duration(&mut env, component)?;
+
+    // Act
+    let result = component.set_nft_auction_config(
+        NonFungibleLocalId::integer(1),
+        Some(AuctionConfig {
+            buffer_minutes: Some(auction_duration),
+            ..Default::default()
+        }),
+        &mut env,
+    );
+
+    // Assert
+    assert!(
+        result.is_err(),
+        "Auction config with a buffer as long as the default duration was set!"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_set_nft_auction_config_without_owner() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, _owner_resource) = create_prepared_test_environment()?;
+
+    // Act
+    let result = component.set_nft_auction_config(
+        NonFungibleLocalId::integer(1),
+        Some(AuctionConfig::default()),
+        &mut env,
+    );
+
+    // Assert
+    assert!(
+        result.is_err(),
+        "Auction config was set without an owner badge!"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn can_update_starting_price() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(proof, &mut env)?;
+
+    // Act
+    component.update_starting_price(dec!(500), &mut env)?;
+
+    // Assert
+    let starting_price = get_state_starting_price(&mut env, component)?;
+
+    assert!(
+        starting_price == dec!(500),
+        "Starting price has not changed!"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_update_starting_price_to_zero() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(proof, &mut env)?;
+
+    // Act
+    let result = component.update_starting_price(dec!(0), &mut env);
+
+    // Assert
+    assert!(result.is_err(), "Starting price was changed to 0!");
+
+    Ok(())
+}
+
+#[test]
+fn cannot_update_starting_price_without_owner() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, _owner_resource) = create_prepared_test_environment()?;
+
+    // Act
+    let result = component.update_starting_price(dec!(500), &mut env);
+
+    // Assert
+    assert!(
+        result.is_err(),
+        "Starting price was changed without an owner badge!"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_bid_below_starting_price() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.set_nft_starting_price(NonFungibleLocalId::integer(1), Some(dec!(2000)), &mut env)?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+
+    // Act
+    let result = component.bid(1, xrd_bucket.into(), account, &mut env);
+
+    // Assert
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+
+    assert!(
+        current_auction.starting_price == dec!(2000),
+        "Starting price override was not applied"
+    );
+    assert!(result.is_err(), "Could bid below the starting price");
+
+    Ok(())
+}
+
+#[test]
+fn can_buy_now_dutch_auction() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.set_nft_auction_type(
+        NonFungibleLocalId::integer(1),
+        AuctionType::Dutch(DutchAuction {
+            start_price: dec!(1000),
+            floor_price: dec!(100),
+            decay: PriceDecay::Linear,
+        }),
+        &mut env,
+    )?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    let auction_duration = get_state_auction_duration(&mut env, component)?;
+    env.set_current_time(
+        current_auction
+            .start_timestamp
+            .add_minutes(auction_duration as i64 / 2)
+            .expect("Could not add time"),
+    ); // Halfway through the auction, so the price is 550
+
+    // Act
+    let (change, nft) = component.buy_now(1, xrd_bucket.into(), account, &mut env)?;
+
+    // Assert
+    assert!(nft.is_some(), "Did not get the NFT");
+    assert!(
+        change.amount(&mut env)? == dec!(450) + dec!(550) * dec!(0.05),
+        "Did not get the change and settlement reward"
+    );
+
+    let current_auction = component
+        .get_current_auction(2, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    assert!(current_auction.id == 2, "Dutch auction wasn't settled");
+
+    Ok(())
+}
+
+#[test]
+fn cannot_buy_now_below_dutch_auction_price() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.set_nft_auction_type(
+        NonFungibleLocalId::integer(1),
+        AuctionType::Dutch(DutchAuction {
+            start_price: dec!(1000),
+            floor_price: dec!(100),
+            decay: PriceDecay::Stepped { step_minutes: 60 },
+        }),
+        &mut env,
+    )?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(500), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+
+    // Act
+    let result = component.buy_now(1, xrd_bucket.into(), account, &mut env);
+
+    // Assert
+    assert!(result.is_err(), "Could buy below the current price");
+
+    Ok(())
+}
+
+#[test]
+fn cannot_set_dutch_auction_floor_below_reserve_price() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.update_reserve_price(dec!(500), &mut env)?;
+
+    // Act
+    let result = component.set_nft_auction_type(
+        NonFungibleLocalId::integer(1),
+        AuctionType::Dutch(DutchAuction {
+            start_price: dec!(1000),
+            floor_price: dec!(100),
+            decay: PriceDecay::Linear,
+        }),
+        &mut env,
+    );
+
+    // Assert
+    assert!(
+        result.is_err(),
+        "Dutch auction floor price was set below the reserve price"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn can_buy_now_dutch_auction_below_raised_reserve_price() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.set_nft_auction_type(
+        NonFungibleLocalId::integer(1),
+        AuctionType::Dutch(DutchAuction {
+            start_price: dec!(1000),
+            floor_price: dec!(100),
+            decay: PriceDecay::Linear,
+        }),
+        &mut env,
+    )?;
+    component.update_reserve_price(dec!(800), &mut env)?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(current_auction.end_timestamp); // The price is at the floor price
+
+    // Act
+    let (_change, nft) = component.buy_now(1, xrd_bucket.into(), account, &mut env)?;
+
+    // Assert
+    assert!(nft.is_some(), "Did not get the NFT");
+
+    let completed_auction = component
+        .get_completed_auction(1, &mut env)?
+        .expect("No completed auction");
+    assert!(
+        completed_auction.status == AuctionStatus::Settled,
+        "Dutch auction was closed as unsold"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_bid_on_dutch_auction() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.set_nft_auction_type(
+        NonFungibleLocalId::integer(1),
+        AuctionType::Dutch(DutchAuction {
+            start_price: dec!(1000),
+            floor_price: dec!(100),
+            decay: PriceDecay::Exponential {
+                decay_per_minute: dec!(0.01),
+            },
+        }),
+        &mut env,
+    )?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+
+    // Act
+    let result = component.bid(1, xrd_bucket.into(), account, &mut env);
+
+    // Assert
+    assert!(result.is_err(), "Could bid on a Dutch auction");
+
+    Ok(())
+}
+
+#[test]
+fn can_commit_and_reveal_sealed_bid() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.set_nft_auction_type(
+        NonFungibleLocalId::integer(1),
+        AuctionType::SealedBid(SealedBidAuction {
+            pricing: SealedBidPricing::FirstPrice,
+            reveal_duration_minutes: 60,
+        }),
+        &mut env,
+    )?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1500), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+
+    // Act
+    component.commit_bid(
+        1,
+        SealedBid::commitment(dec!(1000), "salt"),
+        xrd_bucket.into(),
+        account,
+        &mut env,
+    )?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(current_auction.commit_end_timestamp.unwrap());
+
+    component.reveal_bid(1, dec!(1000), "salt".to_string(), account, &mut env)?;
+
+    // Assert
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+
+    assert!(
+        current_auction.highest_bid == Some(dec!(1000)),
+        "Revealed bid is not the highest bid"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_reveal_sealed_bid_with_wrong_salt() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.set_nft_auction_type(
+        NonFungibleLocalId::integer(1),
+        AuctionType::SealedBid(SealedBidAuction {
+            pricing: SealedBidPricing::FirstPrice,
+            reveal_duration_minutes: 60,
+        }),
+        &mut env,
+    )?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.commit_bid(
+        1,
+        SealedBid::commitment(dec!(1000), "salt"),
+        xrd_bucket.into(),
+        account,
+        &mut env,
+    )?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(current_auction.commit_end_timestamp.unwrap());
+
+    // Act
+    let result = component.reveal_bid(1, dec!(1000), "pepper".to_string(), account, &mut env);
+
+    // Assert
+    assert!(result.is_err(), "Could reveal bid with the wrong salt");
+
+    Ok(())
+}
+
+#[test]
+fn sealed_bid_winner_pays_second_price() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.set_nft_auction_type(
+        NonFungibleLocalId::integer(1),
+        AuctionType::SealedBid(SealedBidAuction {
+            pricing: SealedBidPricing::SecondPrice,
+            reveal_duration_minutes: 60,
+        }),
+        &mut env,
+    )?;
+
+    let xrd_bucket1 = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+    let xrd_bucket2 = BucketFactory::create_fungible_bucket(XRD, dec!(800), Mock, &mut env)?;
+
+    let account1 = create_account(&mut env, XRD)?;
+    let account2 = create_account(&mut env, XRD)?;
+    let account_proof1 = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    let account_proof2 = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof1, &mut env)?;
+    LocalAuthZone::push(account_proof2, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.commit_bid(
+        1,
+        SealedBid::commitment(dec!(1000), "salt1"),
+        xrd_bucket1.into(),
+        account1,
+        &mut env,
+    )?;
+    component.commit_bid(
+        1,
+        SealedBid::commitment(dec!(800), "salt2"),
+        xrd_bucket2.into(),
+        account2,
+        &mut env,
+    )?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(current_auction.commit_end_timestamp.unwrap());
+
+    component.reveal_bid(1, dec!(1000), "salt1".to_string(), account1, &mut env)?;
+    component.reveal_bid(1, dec!(800), "salt2".to_string(), account2, &mut env)?;
+
+    env.set_current_time(current_auction.end_timestamp);
+
+    // Act
+    let (reward, nft) = component.settle_auction(1, account1, &mut env)?;
+
+    // Assert
+    assert!(nft.is_some(), "Winner did not get the NFT");
+    assert!(
+        reward.amount(&mut env)? == dec!(800) * dec!(0.05),
+        "Winner did not pay the second highest bid"
+    );
+
+    let completed_auction = component
+        .get_completed_auction(1, &mut env)?
+        .expect("No completed auction");
+    assert!(
+        completed_auction.highest_bid == Some(dec!(800)),
+        "Settled price is not the second highest bid"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn can_buy_now_with_buy_now_price() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.delete_nft(NonFungibleLocalId::integer(1), &mut env)?;
+    component.delete_nft(NonFungibleLocalId::integer(2), &mut env)?;
+    mint_nfts_with_buy_now_price(&mut env, component, dec!(2000))?; // Only NFT 3 is left
+
+    let xrd_bucket1 = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+    let xrd_bucket2 = BucketFactory::create_fungible_bucket(XRD, dec!(2500), Mock, &mut env)?;
+
+    let account1 = create_account(&mut env, XRD)?;
+    let account2 = create_account(&mut env, XRD)?;
+    let account_proof1 = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    let account_proof2 = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof1, &mut env)?;
+    LocalAuthZone::push(account_proof2, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket1.into(), account1, &mut env)?;
+
+    // Act
+    let (change, nft) = component.buy_now(1, xrd_bucket2.into(), account2, &mut env)?;
+
+    // Assert
+    assert!(nft.is_some(), "Did not get the NFT");
+    assert!(
+        change.amount(&mut env)? == dec!(500) + dec!(2000) * dec!(0.05),
+        "Did not get the change and settlement reward"
+    );
+
+    let completed_auction = component
+        .get_completed_auction(1, &mut env)?
+        .expect("No completed auction");
+    assert!(
+        completed_auction.highest_bid == Some(dec!(2000)),
+        "Auction was not settled at the buy now price"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_buy_now_below_reserve_price() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.delete_nft(NonFungibleLocalId::integer(1), &mut env)?;
+    component.delete_nft(NonFungibleLocalId::integer(2), &mut env)?;
+    mint_nfts_with_buy_now_price(&mut env, component, dec!(2000))?;
+    component.update_reserve_price(dec!(3000), &mut env)?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(2000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+
+    // Act
+    let result = component.buy_now(1, xrd_bucket.into(), account, &mut env);
+
+    // Assert
+    assert!(
+        result.is_err(),
+        "Could buy now below the reserve price"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_buy_now_when_highest_bid_is_not_below_buy_now_price() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.delete_nft(NonFungibleLocalId::integer(1), &mut env)?;
+    component.delete_nft(NonFungibleLocalId::integer(2), &mut env)?;
+    mint_nfts_with_buy_now_price(&mut env, component, dec!(2000))?;
+
+    let xrd_bucket1 = BucketFactory::create_fungible_bucket(XRD, dec!(2000), Mock, &mut env)?;
+    let xrd_bucket2 = BucketFactory::create_fungible_bucket(XRD, dec!(2500), Mock, &mut env)?;
+
+    let account1 = create_account(&mut env, XRD)?;
+    let account2 = create_account(&mut env, XRD)?;
+    let account_proof1 = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    let account_proof2 = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof1, &mut env)?;
+    LocalAuthZone::push(account_proof2, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket1.into(), account1, &mut env)?;
+
+    // Act
+    let result = component.buy_now(1, xrd_bucket2.into(), account2, &mut env);
+
+    // Assert
+    assert!(
+        result.is_err(),
+        "Could buy now while the highest bid was not below the buy now price"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_buy_now_when_proxy_max_bid_is_not_below_buy_now_price() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.delete_nft(NonFungibleLocalId::integer(1), &mut env)?;
+    component.delete_nft(NonFungibleLocalId::integer(2), &mut env)?;
+    mint_nfts_with_buy_now_price(&mut env, component, dec!(2000))?;
+
+    let xrd_bucket1 = BucketFactory::create_fungible_bucket(XRD, dec!(3000), Mock, &mut env)?;
+    let xrd_bucket2 = BucketFactory::create_fungible_bucket(XRD, dec!(2000), Mock, &mut env)?;
+
+    let account1 = create_account(&mut env, XRD)?;
+    let account2 = create_account(&mut env, XRD)?;
+    let account_proof1 = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    let account_proof2 = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof1, &mut env)?;
+    LocalAuthZone::push(account_proof2, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.place_max_bid(1, xrd_bucket1.into(), account1, &mut env)?;
+
+    // Act
+    let result = component.buy_now(1, xrd_bucket2.into(), account2, &mut env);
+
+    // Assert
+    assert!(
+        result.is_err(),
+        "Could buy now while the proxy max bid was not below the buy now price"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn proxy_bid_outbids_new_bid() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, _owner_resource) = create_prepared_test_environment()?;
+
+    let xrd_bucket1 = BucketFactory::create_fungible_bucket(XRD, dec!(2000), Mock, &mut env)?;
+    let xrd_bucket2 = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account1 = create_account(&mut env, XRD)?;
+    let account2 = create_account(&mut env, XRD)?;
+    let account_proof1 = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    let account_proof2 = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof1, &mut env)?;
+    LocalAuthZone::push(account_proof2, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.place_max_bid(1, xrd_bucket1.into(), account1, &mut env)?;
+
+    // Act
+    let (returned_bid, _) = component.bid(1, xrd_bucket2.into(), account2, &mut env)?;
+
+    // Assert
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    let minimum_bid_increase = get_state_minimum_bid_increase(&mut env, component)?;
+
+    assert!(
+        returned_bid.expect("Bid was not returned").amount(&mut env)? == dec!(1000),
+        "Outbid bid was not returned"
+    );
+    assert!(
+        current_auction.highest_bid == Some(dec!(1000) + minimum_bid_increase),
+        "Proxy bid did not outbid the new bid by the minimum increase"
+    );
+    assert!(
+        current_auction.latest_bids.last().unwrap().is_proxy,
+        "Latest bid was not a proxy bid"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn bid_above_proxy_max_bid_takes_the_lead() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, _owner_resource) = create_prepared_test_environment()?;
+
+    let xrd_bucket1 = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+    let xrd_bucket2 = BucketFactory::create_fungible_bucket(XRD, dec!(1500), Mock, &mut env)?;
+
+    let account1 = create_account(&mut env, XRD)?;
+    let account2 = create_account(&mut env, XRD)?;
+    let account_proof1 = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    let account_proof2 = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof1, &mut env)?;
+    LocalAuthZone::push(account_proof2, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.place_max_bid(1, xrd_bucket1.into(), account1, &mut env)?;
+
+    // Act
+    let (returned_bid, _) = component.bid(1, xrd_bucket2.into(), account2, &mut env)?;
+
+    // Assert
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+
+    assert!(returned_bid.is_none(), "Bid was returned");
+    assert!(
+        current_auction.highest_bid == Some(dec!(1500)),
+        "New bid did not take the lead"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn proxy_bid_settles_at_current_price() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, _owner_resource) = create_prepared_test_environment()?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(2000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.place_max_bid(1, xrd_bucket.into(), account, &mut env)?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(
+        current_auction
+            .end_timestamp
+            .add_minutes(1)
+            .expect("Could not add time"),
+    );
+
+    // Act
+    let (reward, _) = component.settle_auction(1, account, &mut env)?;
+
+    // Assert
+    let starting_price = get_state_starting_price(&mut env, component)?;
+    let completed_auction = component
+        .get_completed_auction(1, &mut env)?
+        .expect("No completed auction");
+
+    assert!(
+        completed_auction.highest_bid == Some(starting_price),
+        "Proxy bid did not settle at the starting price"
+    );
+    assert!(
+        reward.amount(&mut env)? == starting_price * dec!(0.05),
+        "Reward was not based on the settled price"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn proxy_bid_settles_at_reserve_price() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(proof, &mut env)?;
+    component.update_reserve_price(dec!(500), &mut env)?;
+    component.update_starting_price(dec!(10), &mut env)?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.place_max_bid(1, xrd_bucket.into(), account, &mut env)?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(
+        current_auction
+            .end_timestamp
+            .add_minutes(1)
+            .expect("Could not add time"),
+    );
+
+    // Act
+    component.settle_auction(1, account, &mut env)?;
+
+    // Assert
+    let completed_auction = component
+        .get_completed_auction(1, &mut env)?
+        .expect("No completed auction");
+
+    assert!(
+        current_auction.highest_bid == Some(dec!(500)),
+        "Proxy bid was not raised to the reserve price"
+    );
+    assert!(
+        completed_auction.status == AuctionStatus::Settled
+            && completed_auction.highest_bid == Some(dec!(500)),
+        "Proxy bid did not settle at the reserve price"
+    );
+    assert!(
+        get_locker_amount(&mut env, component, account, XRD)? == dec!(500),
+        "Unused proxy maximum was not refunded"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn can_increase_bid() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, _owner_resource) = create_prepared_test_environment()?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+    let increase_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(500), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
+
+    // Act
+    component.increase_bid(1, increase_bucket.into(), account, &mut env)?;
+
+    // Assert
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+
+    assert!(
+        current_auction.highest_bid == Some(dec!(1500)),
+        "Highest bid was not increased"
+    );
+    assert!(current_auction.bid_count == 2, "Increase was not recorded");
+
+    Ok(())
+}
+
+#[test]
+fn cannot_increase_bid_when_not_highest_bidder() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, _owner_resource) = create_prepared_test_environment()?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+    let increase_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(500), Mock, &mut env)?;
+
+    let account1 = create_account(&mut env, XRD)?;
+    let account2 = create_account(&mut env, XRD)?;
+    let account_proof1 = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    let account_proof2 = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof1, &mut env)?;
+    LocalAuthZone::push(account_proof2, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket.into(), account1, &mut env)?;
+
+    // Act
+    let result = component.increase_bid(1, increase_bucket.into(), account2, &mut env);
+
+    // Assert
+    assert!(
+        result.is_err(),
+        "Could increase the bid without being the highest bidder"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn can_run_concurrent_auctions() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.update_max_active_auctions(2, &mut env)?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    // Act
+    component.start_new_auction(&mut env)?;
+    component.start_new_auction(&mut env)?;
+    component.bid(2, xrd_bucket.into(), account, &mut env)?;
+
+    // Assert
+    let active_auctions = component.get_active_auctions(&mut env)?;
+    assert!(active_auctions.len() == 2, "Auctions are not running concurrently");
+
+    let first_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    let second_auction = component
+        .get_current_auction(2, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    assert!(first_auction.highest_bid.is_none(), "Bid was placed on the wrong auction");
+    assert!(
+        second_auction.highest_bid == Some(dec!(1000)),
+        "Bid was not placed on the second auction"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_start_auction_when_all_slots_are_in_use() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, _owner_resource) = create_prepared_test_environment()?;
+
+    component.start_new_auction(&mut env)?;
+
+    // Act
+    let result = component.start_new_auction(&mut env);
+
+    // Assert
+    assert!(result.is_err(), "Could start more auctions than there are slots");
+
+    Ok(())
+}
+
+#[test]
+fn cannot_update_max_active_auctions_without_owner() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, _owner_resource) = create_prepared_test_environment()?;
+
+    // Act
+    let result = component.update_max_active_auctions(2, &mut env);
+
+    // Assert
+    assert!(
+        result.is_err(),
+        "Maximum number of active auctions was changed without an owner badge!"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn can_auction_lot_of_nfts() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.create_lot(
+        vec![NonFungibleLocalId::integer(1), NonFungibleLocalId::integer(2)],
+        &mut env,
+    )?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(current_auction.end_timestamp);
+
+    // Act
+    let (_reward, nft) = component.settle_auction(1, account, &mut env)?;
+
+    // Assert
+    assert!(
+        current_auction.nfts
+            == vec![NonFungibleLocalId::integer(1), NonFungibleLocalId::integer(2)],
+        "Auction does not contain the lot"
+    );
+    assert!(
+        nft.expect("Did not get the lot").amount(&mut env)? == dec!(2),
+        "Did not get all NFTs in the lot"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_create_lot_without_owner() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, _owner_resource) = create_prepared_test_environment()?;
+
+    // Act
+    let result = component.create_lot(
+        vec![NonFungibleLocalId::integer(1), NonFungibleLocalId::integer(2)],
+        &mut env,
+    );
+
+    // Assert
+    assert!(result.is_err(), "Could create a lot without an owner badge!");
+
+    Ok(())
+}
+
+#[test]
+fn can_update_settler_reward() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+
+    // Act
+    component.update_settler_reward(dec!(0.1), Some(dec!(100)), Some(dec!(5)), &mut env)?;
+
+    // Assert
+    let settler_reward = component.get_settler_reward(&mut env)?;
+    assert!(
+        settler_reward
+            == SettlerReward {
+                percentage: dec!(0.1),
+                cap: Some(dec!(100)),
+                floor: Some(dec!(5)),
+            },
+        "Settler reward was not updated"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_update_settler_reward_above_maximum() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+
+    // Act
+    let result = component.update_settler_reward(dec!(0.5), None, None, &mut env);
+
+    // Assert
+    assert!(result.is_err(), "Settler reward percentage was set above the maximum");
+
+    Ok(())
+}
+
+#[test]
+fn cannot_update_settler_reward_without_owner() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, _owner_resource) = create_prepared_test_environment()?;
+
+    // Act
+    let result = component.update_settler_reward(dec!(0.1), None, None, &mut env);
+
+    // Assert
+    assert!(
+        result.is_err(),
+        "Settler reward was changed without an owner badge!"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn settler_reward_is_capped() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.update_settler_reward(dec!(0.05), Some(dec!(10)), None, &mut env)?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(current_auction.end_timestamp);
+
+    // Act
+    let (reward, _nft) = component.settle_auction(1, account, &mut env)?;
+
+    // Assert
+    assert!(
+        reward.amount(&mut env)? == dec!(10),
+        "Settler reward was not capped"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn swap_is_skipped_when_output_is_too_low() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.update_swap_protection(
+        Some(SwapProtection {
+            reference_price: dec!(2),
+            max_slippage: dec!(0.1),
+        }),
+        &mut env,
+    )?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(current_auction.end_timestamp);
+
+    // Act
+    component.settle_auction(1, account, &mut env)?;
+
+    // Assert
+    assert!(
+        component.get_treasury_amount(&mut env)? == dec!(950),
+        "XRD was not kept in the XRD treasury"
+    );
+    assert!(
+        component.get_profit_amount(&mut env)? == dec!(0),
+        "Swap was not skipped"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn swap_happens_within_max_slippage() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.update_swap_protection(
+        Some(SwapProtection {
+            reference_price: dec!(1),
+            max_slippage: dec!(0.05),
+        }),
+        &mut env,
+    )?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(current_auction.end_timestamp);
+
+    // Act
+    component.settle_auction(1, account, &mut env)?;
+
+    // Assert
+    assert!(
+        is_about_equal(component.get_profit_amount(&mut env)?, dec!(950)),
+        "Swap did not happen"
+    );
+    assert!(
+        component.get_treasury_amount(&mut env)? == dec!(0),
+        "XRD was kept in the XRD treasury"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_update_swap_protection_without_owner() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, _owner_resource) = create_prepared_test_environment()?;
+
+    // Act
+    let result = component.update_swap_protection(None, &mut env);
+
+    // Assert
+    assert!(
+        result.is_err(),
+        "Swap protection was changed without an owner badge!"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn swap_output_depends_on_pool_reserves() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let early_resource_address = get_state_early_address(&mut env, component)?;
+    let pool_address = instantiate_mock_pool_with_reserves(
+        &mut env,
+        SwapAdapter::OciswapV2,
+        early_resource_address,
+        dec!(1000),
+        dec!(1000),
+    )?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.update_swap_pool(SwapAdapter::OciswapV2, pool_address, &mut env)?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(current_auction.end_timestamp);
+
+    // Act
+    component.settle_auction(1, account, &mut env)?;
+
+    // Assert
+    assert!(
+        is_about_equal(
+            component.get_profit_amount(&mut env)?,
+            dec!(1000) * dec!(950) / dec!(1950)
+        ),
+        "Swap output did not follow the pool reserves"
+    ); // 950 XRD drains about half of the EARLY in a pool of 1000 XRD and 1000 EARLY
+
+    Ok(())
+}
+
+#[test]
+fn swap_is_skipped_when_pool_is_drained() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let early_resource_address = get_state_early_address(&mut env, component)?;
+    let pool_address = instantiate_mock_pool_with_reserves(
+        &mut env,
+        SwapAdapter::OciswapV2,
+        early_resource_address,
+        dec!(1000),
+        dec!(1000),
+    )?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.update_swap_pool(SwapAdapter::OciswapV2, pool_address, &mut env)?;
+    component.update_swap_protection(
+        Some(SwapProtection {
+            reference_price: dec!(1),
+            max_slippage: dec!(0.05),
+        }),
+        &mut env,
+    )?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(current_auction.end_timestamp);
+
+    // Act
+    component.settle_auction(1, account, &mut env)?;
+
+    // Assert
+    assert!(
+        component.get_treasury_amount(&mut env)? == dec!(950),
+        "XRD was not kept in the XRD treasury"
+    );
+    assert!(
+        component.get_profit_amount(&mut env)? == dec!(0),
+        "Swap on a drained pool was not skipped"
+    );
+
+    Ok(())
+}
+
+fn settle_auction_with_swap_adapter(adapter: SwapAdapter) -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let early_resource_address = get_state_early_address(&mut env, component)?;
+    let pool_address = instantiate_mock_pool(&mut env, adapter.clone(), early_resource_address)?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.update_swap_pool(adapter, pool_address, &mut env)?;
+    component.update_swap_protection(
+        Some(SwapProtection {
+            reference_price: dec!(1),
+            max_slippage: dec!(0.05),
+        }),
+        &mut env,
+    )?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(current_auction.end_timestamp);
+
+    // Act
+    component.settle_auction(1, account, &mut env)?;
+
+    // Assert
+    assert!(
+        is_about_equal(component.get_profit_amount(&mut env)?, dec!(950)),
+        "Swap did not happen"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn can_swap_with_ociswap_v2_pool() -> Result<(), RuntimeError> {
+    settle_auction_with_swap_adapter(SwapAdapter::OciswapV2)
+}
+
+#[test]
+fn can_swap_with_defiplaza_pool() -> Result<(), RuntimeError> {
+    settle_auction_with_swap_adapter(SwapAdapter::DefiPlaza)
+}
+
+#[test]
+fn can_swap_with_caviarnine_pool() -> Result<(), RuntimeError> {
+    settle_auction_with_swap_adapter(SwapAdapter::CaviarNine)
+}
+
+#[test]
+fn can_execute_deferred_swap() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.update_deferred_swaps(
+        Some(DeferredSwaps {
+            bounty_percentage: dec!(0.01),
+            max_swap_amount: Some(dec!(500)),
+        }),
+        &mut env,
+    )?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(current_auction.end_timestamp);
+
+    component.settle_auction(1, account, &mut env)?;
+    assert!(
+        component.get_treasury_amount(&mut env)? == dec!(950),
+        "Proceeds were not kept in the treasury"
+    );
+
+    // Act
+    let bounty = component.execute_swap(dec!(500), &mut env)?;
+
+    // Assert
+    assert!(bounty.amount(&mut env)? == dec!(5), "Did not get the bounty");
+    assert!(
+        is_about_equal(component.get_profit_amount(&mut env)?, dec!(495)),
+        "Swap did not happen"
+    );
+    assert!(
+        component.get_treasury_amount(&mut env)? == dec!(450),
+        "Swapped XRD was not taken from the treasury"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_execute_swap_above_max_swap_amount() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.update_deferred_swaps(
+        Some(DeferredSwaps {
+            bounty_percentage: dec!(0.01),
+            max_swap_amount: Some(dec!(500)),
+        }),
+        &mut env,
+    )?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(current_auction.end_timestamp);
+
+    component.settle_auction(1, account, &mut env)?;
+
+    // Act
+    let result = component.execute_swap(dec!(900), &mut env);
+
+    // Assert
+    assert!(result.is_err(), "Could swap more than the maximum swap amount");
+
+    Ok(())
+}
+
+#[test]
+fn cannot_execute_swap_without_keeper_or_owner() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, _owner_resource) = create_prepared_test_environment()?;
+
+    // Act
+    let result = component.execute_swap(dec!(1), &mut env);
+
+    // Assert
+    assert!(
+        result.is_err(),
+        "Could execute a swap without the keeper or owner badge!"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn profits_are_split_over_destinations() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.update_profit_split(
+        vec![
+            ProfitShare {
+                destination: ProfitDestination::KeepXrd,
+                percentage: dec!(0.2),
+            },
+            ProfitShare {
+                destination: ProfitDestination::SwapToEarly,
+                percentage: dec!(0.8),
+            },
+        ],
+        &mut env,
+    )?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(current_auction.end_timestamp);
+
+    // Act
+    component.settle_auction(1, account, &mut env)?;
+
+    // Assert
+    assert!(
+        component.get_xrd_profit_amount(&mut env)? == dec!(190),
+        "XRD share was not kept"
+    );
+    assert!(
+        is_about_equal(component.get_profit_amount(&mut env)?, dec!(760)),
+        "EARLY share was not swapped"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn burn_share_is_deferred_with_swaps() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let black_hole_address = instantiate_black_hole(&mut env)?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.update_early_burn(
+        Some(EarlyBurn {
+            percentage: dec!(0.5),
+            burn_component: Some(black_hole_address),
+        }),
+        &mut env,
+    )?;
+    component.update_deferred_swaps(
+        Some(DeferredSwaps {
+            bounty_percentage: dec!(0),
+            max_swap_amount: None,
+        }),
+        &mut env,
+    )?;
+    component.update_profit_split(
+        vec![
+            ProfitShare {
+                destination: ProfitDestination::Burn,
+                percentage: dec!(0.2),
+            },
+            ProfitShare {
+                destination: ProfitDestination::SwapToEarly,
+                percentage: dec!(0.8),
+            },
+        ],
+        &mut env,
+    )?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(current_auction.end_timestamp);
+
+    component.settle_auction(1, account, &mut env)?;
+    assert!(
+        component.get_pending_burn_amount(&mut env)? == dec!(190),
+        "Burn share was not kept as pending burn"
+    );
+    assert!(
+        component.get_total_early_burned(&mut env)? == dec!(0),
+        "Burn share was swapped right away"
+    );
+
+    // Act
+    component.execute_swap(dec!(950), &mut env)?;
+
+    // Assert
+    assert!(
+        is_about_equal(
+            component.get_total_early_burned(&mut env)?,
+            dec!(190) + dec!(380)
+        ),
+        "Pending burn was not burned"
+    ); // All EARLY of the burn share and half of the EARLY of the swap share
+    assert!(
+        component.get_pending_burn_amount(&mut env)? == dec!(0),
+        "Pending burn was not cleared"
+    );
+    assert!(
+        is_about_equal(component.get_profit_amount(&mut env)?, dec!(380)),
+        "EARLY share was not swapped"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_update_profit_split_not_adding_up_to_one() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+
+    // Act
+    let result = component.update_profit_split(
+        vec![
+            ProfitShare {
+                destination: ProfitDestination::KeepXrd,
+                percentage: dec!(0.2),
+            },
+            ProfitShare {
+                destination: ProfitDestination::SwapToEarly,
+                percentage: dec!(0.7),
+            },
+        ],
+        &mut env,
+    );
+
+    // Assert
+    assert!(result.is_err(), "Profit split doesn't add up to 1");
+
+    Ok(())
+}
+
+#[test]
+fn cannot_update_profit_split_without_owner() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, _owner_resource) = create_prepared_test_environment()?;
+
+    // Act
+    let result = component.update_profit_split(
+        vec![ProfitShare {
+            destination: ProfitDestination::KeepXrd,
+            percentage: dec!(1),
+        }],
+        &mut env,
+    );
+
+    // Assert
+    assert!(
+        result.is_err(),
+        "Profit split was changed without an owner badge!"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn purchased_early_is_partly_burned() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let black_hole_address = instantiate_black_hole(&mut env)?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.update_early_burn(
+        Some(EarlyBurn {
+            percentage: dec!(0.1),
+            burn_component: Some(black_hole_address),
+        }),
+        &mut env,
+    )?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(current_auction.end_timestamp);
+
+    // Act
+    component.settle_auction(1, account, &mut env)?;
+
+    // Assert
+    assert!(
+        is_about_equal(component.get_total_early_burned(&mut env)?, dec!(95)),
+        "EARLY was not burned"
+    );
+    assert!(
+        is_about_equal(component.get_profit_amount(&mut env)?, dec!(855)),
+        "Wrong amount of EARLY was kept"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_update_early_burn_without_owner() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, _owner_resource) = create_prepared_test_environment()?;
+
+    // Act
+    let result = component.update_early_burn(None, &mut env);
+
+    // Assert
+    assert!(
+        result.is_err(),
+        "Early burn was changed without an owner badge!"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn holders_can_claim_dividends() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.update_dividend_percentage(dec!(0.1), &mut env)?;
+
+    let xrd_bucket1 = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+    let xrd_bucket2 = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    // The first sale has no holders to pay dividends to
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket1.into(), account, &mut env)?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(current_auction.end_timestamp);
+
+    let (_reward, nft) = component.settle_auction(1, account, &mut env)?;
+    let nft_proof = nft
+        .expect("Did not get the NFT")
+        .create_proof_of_all(&mut env)?;
+
+    // The second sale pays dividends to the holder of NFT 1
+    component.bid(2, xrd_bucket2.into(), account, &mut env)?;
+
+    let current_auction = component
+        .get_current_auction(2, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(current_auction.end_timestamp);
+
+    component.settle_auction(2, account, &mut env)?;
+
+    // Act
+    let dividends = component.claim_dividends(nft_proof, &mut env)?;
+
+    // Assert
+    assert!(
+        is_about_equal(dividends.amount(&mut env)?, dec!(95)),
+        "Did not get the dividends"
+    );
+    assert!(
+        component.get_claimable_dividends(vec![NonFungibleLocalId::integer(1)], &mut env)?
+            == dec!(0),
+        "Dividends can be claimed twice"
+    );
+    assert!(
+        component.get_claimable_dividends(vec![NonFungibleLocalId::integer(2)], &mut env)?
+            == dec!(0),
+        "Winner got dividends from their own payment"
+    );
+    assert!(
+        is_about_equal(
+            component.get_profit_amount(&mut env)?,
+            dec!(950) + dec!(855)
+        ),
+        "Dividends were not taken from the profit"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_update_dividend_percentage_without_owner() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, _owner_resource) = create_prepared_test_environment()?;
+
+    // Act
+    let result = component.update_dividend_percentage(dec!(0.1), &mut env);
+
+    // Assert
+    assert!(
+        result.is_err(),
+        "Dividend percentage was changed without an owner badge!"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn bid_increase_must_meet_percentage_of_highest_bid() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.update_minimum_bid_increase_percentage(dec!(0.1), &mut env)?;
+
+    let xrd_bucket1 = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+    let xrd_bucket2 = BucketFactory::create_fungible_bucket(XRD, dec!(1050), Mock, &mut env)?;
+
+    let account1 = create_account(&mut env, XRD)?;
+    let account2 = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket1.into(), account1, &mut env)?;
+
+    // Act
+    let result = component.bid(1, xrd_bucket2.into(), account2, &mut env);
+
+    // Assert
+    assert!(result.is_err(), "Bid increase below the percentage was accepted");
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    assert!(
+        current_auction.minimum_next_bid() == dec!(1100),
+        "Minimum next bid is not 10% above the highest bid"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_update_minimum_bid_increase_percentage_without_owner() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, _owner_resource) = create_prepared_test_environment()?;
+
+    // Act
+    let result = component.update_minimum_bid_increase_percentage(dec!(0.1), &mut env);
+
+    // Assert
+    assert!(
+        result.is_err(),
+        "Minimum bid increase percentage was changed without an owner badge!"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn auction_is_not_extended_past_max_extensions() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.update_extension_limits(
+        ExtensionLimits {
+            max_extensions: Some(1),
+            max_extension_minutes: None,
+        },
+        &mut env,
+    )?;
+
+    let xrd_bucket1 = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+    let xrd_bucket2 = BucketFactory::create_fungible_bucket(XRD, dec!(1500), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(current_auction.end_timestamp.add_minutes(-1).unwrap());
+    component.bid(1, xrd_bucket1.into(), account, &mut env)?;
+
+    let extended_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(extended_auction.end_timestamp.add_minutes(-1).unwrap());
+
+    // Act
+    component.bid(1, xrd_bucket2.into(), account, &mut env)?;
+
+    // Assert
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    assert!(
+        current_auction.extension_count == 1,
+        "Auction was extended more than the maximum"
+    );
+    assert!(
+        current_auction.end_timestamp == extended_auction.end_timestamp,
+        "End timestamp was changed"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn auction_is_not_extended_past_hard_end() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.update_extension_limits(
+        ExtensionLimits {
+            max_extensions: None,
+            max_extension_minutes: Some(2),
+        },
+        &mut env,
+    )?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(current_auction.end_timestamp.add_minutes(-1).unwrap());
+
+    // Act
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
+
+    // Assert
+    let hard_end_timestamp = current_auction.end_timestamp.add_minutes(2).unwrap();
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    assert!(
+        current_auction.hard_end_timestamp == Some(hard_end_timestamp),
+        "Hard end timestamp was not set"
+    );
+    assert!(
+        current_auction.end_timestamp == hard_end_timestamp,
+        "Auction was extended past the hard end"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_update_extension_limits_without_owner() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, _owner_resource) = create_prepared_test_environment()?;
+
+    // Act
+    let result = component.update_extension_limits(
+        ExtensionLimits {
+            max_extensions: Some(1),
+            max_extension_minutes: None,
+        },
+        &mut env,
+    );
+
+    // Assert
+    assert!(
+        result.is_err(),
+        "Extension limits were changed without an owner badge!"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn next_auction_starts_after_cooldown() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.update_auction_schedule(
+        AuctionSchedule {
+            daily_start_minutes: None,
+            cooldown_minutes: Some(60),
+        },
+        &mut env,
+    )?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(current_auction.end_timestamp);
+    component.settle_auction(1, account, &mut env)?;
+
+    // Act
+    let early_result = component.start_new_auction(&mut env);
+    env.set_current_time(current_auction.end_timestamp.add_minutes(60).unwrap());
+    let result = component.start_new_auction(&mut env);
+
+    // Assert
+    assert!(early_result.is_err(), "Next auction started during the cooldown");
+    assert!(result.is_ok(), "Next auction did not start after the cooldown");
+
+    let next_auction = component
+        .get_current_auction(2, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    assert!(
+        next_auction.scheduled_start_timestamp
+            == Some(current_auction.end_timestamp.add_minutes(60).unwrap()),
+        "Scheduled start was not set"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cooldown_counts_from_auction_end() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.update_auction_schedule(
+        AuctionSchedule {
+            daily_start_minutes: None,
+            cooldown_minutes: Some(60),
+        },
+        &mut env,
+    )?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(current_auction.end_timestamp.add_minutes(30).unwrap());
+    component.settle_auction(1, account, &mut env)?; // Settled halfway through the cooldown
+
+    // Act
+    env.set_current_time(current_auction.end_timestamp.add_minutes(60).unwrap());
+    let result = component.start_new_auction(&mut env);
+
+    // Assert
+    assert!(
+        result.is_ok(),
+        "Cooldown did not count from the end of the auction"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn next_auction_starts_at_daily_start_time() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    env.set_current_time(Instant::new(20_000 * 24 * 60 * 60 + 12 * 60 * 60)); // 12:00 UTC
+    component.update_auction_schedule(
+        AuctionSchedule {
+            daily_start_minutes: Some(18 * 60),
+            cooldown_minutes: None,
+        },
+        &mut env,
+    )?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    env.set_current_time(Instant::new(20_000 * 24 * 60 * 60 + 18 * 60 * 60)); // 18:00 UTC
+    component.start_new_auction(&mut env)?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(current_auction.end_timestamp);
+
+    // Act
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?; // Settles the auction without bids
+
+    // Assert
+    let next_start_timestamp = component
+        .get_next_start_timestamp(&mut env)?
+        .expect("No next start time");
+    assert!(
+        next_start_timestamp.seconds_since_unix_epoch % (24 * 60 * 60) == 18 * 60 * 60,
+        "Next start is not at the daily start time"
+    );
+    assert!(
+        component.get_current_auction(2, &mut env)?.is_none(),
+        "Next auction started before the daily start time"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn first_auction_waits_for_daily_start_time() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    env.set_current_time(Instant::new(20_000 * 24 * 60 * 60 + 12 * 60 * 60)); // 12:00 UTC
+    component.update_auction_schedule(
+        AuctionSchedule {
+            daily_start_minutes: Some(18 * 60),
+            cooldown_minutes: None,
+        },
+        &mut env,
+    )?;
+
+    // Act
+    let early_result = component.start_new_auction(&mut env);
+    env.set_current_time(Instant::new(20_000 * 24 * 60 * 60 + 18 * 60 * 60)); // 18:00 UTC
+    let result = component.start_new_auction(&mut env);
+
+    // Assert
+    assert!(
+        early_result.is_err(),
+        "First auction started before the daily start time"
+    );
+    assert!(
+        result.is_ok(),
+        "First auction did not start at the daily start time"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn next_auction_is_scheduled_until_its_start() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    env.set_current_time(Instant::new(20_000 * 24 * 60 * 60 + 12 * 60 * 60)); // 12:00 UTC
+    component.update_auction_schedule(
+        AuctionSchedule {
+            daily_start_minutes: Some(18 * 60),
+            cooldown_minutes: None,
+        },
+        &mut env,
+    )?;
+
+    // Act
+    let scheduled_status = component.get_auction_status(1, &mut env)?;
+    env.set_current_time(Instant::new(20_000 * 24 * 60 * 60 + 18 * 60 * 60)); // 18:00 UTC
+    component.start_new_auction(&mut env)?;
+    let live_status = component.get_auction_status(1, &mut env)?;
+
+    // Assert
+    assert!(
+        scheduled_status == Some(AuctionStatus::Scheduled),
+        "Next auction was not scheduled before the daily start time"
+    );
+    assert!(
+        live_status == Some(AuctionStatus::Live),
+        "Auction is not live after it started"
+    );
+    assert!(
+        component.get_auction_status(2, &mut env)?.is_none(),
+        "Next auction is scheduled while it can start right away"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_update_auction_schedule_without_owner() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, _owner_resource) = create_prepared_test_environment()?;
+
+    // Act
+    let result = component.update_auction_schedule(
+        AuctionSchedule {
+            daily_start_minutes: None,
+            cooldown_minutes: Some(60),
+        },
+        &mut env,
+    );
+
+    // Assert
+    assert!(
+        result.is_err(),
+        "Auction schedule was changed without an owner badge!"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn can_cancel_auction() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+
+    // Act
+    component.cancel_current_auction(1, NftPlacement::Front, &mut env)?;
+
+    // Assert
+    assert!(
+        component.get_current_auction(1, &mut env)?.is_none(),
+        "Auction is still active"
+    );
+
+    let completed_auction = component
+        .get_completed_auction(1, &mut env)?
+        .expect("No completed auction");
+    assert!(
+        completed_auction.status == AuctionStatus::Cancelled,
+        "Auction was not recorded as cancelled"
+    );
+
+    let available_nfts = get_state_available_nfts_list(&mut env, component)?;
+    assert!(
+        available_nfts[0] == NonFungibleLocalId::integer(1),
+        "NFT was not put back at the front of the queue"
+    );
+
+    assert!(
+        get_bid_vault_amount(&mut env, component, 1)? == dec!(0),
+        "Bid vault is not empty"
+    );
+    assert!(
+        get_locker_amount(&mut env, component, account, XRD)? == dec!(1000),
+        "Bid was not refunded"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn can_cancel_sealed_bid_auction() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.set_nft_auction_type(
+        NonFungibleLocalId::integer(1),
+        AuctionType::SealedBid(SealedBidAuction {
+            pricing: SealedBidPricing::FirstPrice,
+            reveal_duration_minutes: 60,
+        }),
+        &mut env,
+    )?;
+
+    let xrd_bucket1 = BucketFactory::create_fungible_bucket(XRD, dec!(1500), Mock, &mut env)?;
+    let xrd_bucket2 = BucketFactory::create_fungible_bucket(XRD, dec!(800), Mock, &mut env)?;
+
+    let account1 = create_account(&mut env, XRD)?;
+    let account2 = create_account(&mut env, XRD)?;
+    let account_proof1 = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    let account_proof2 = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof1, &mut env)?;
+    LocalAuthZone::push(account_proof2, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.commit_bid(
+        1,
+        SealedBid::commitment(dec!(1000), "salt1"),
+        xrd_bucket1.into(),
+        account1,
+        &mut env,
+    )?;
+    component.commit_bid(
+        1,
+        SealedBid::commitment(dec!(800), "salt2"),
+        xrd_bucket2.into(),
+        account2,
+        &mut env,
+    )?;
+
+    // Act
+    component.cancel_current_auction(1, NftPlacement::Back, &mut env)?;
+
+    // Assert
+    let completed_auction = component
+        .get_completed_auction(1, &mut env)?
+        .expect("No completed auction");
+    assert!(
+        completed_auction.status == AuctionStatus::Cancelled,
+        "Auction was not recorded as cancelled"
+    );
+
+    assert!(
+        get_bid_vault_amount(&mut env, component, 1)? == dec!(0),
+        "Bid vault is not empty"
+    );
+    assert!(
+        get_locker_amount(&mut env, component, account1, XRD)? == dec!(1500),
+        "Deposit of the first bidder was not refunded"
+    );
+    assert!(
+        get_locker_amount(&mut env, component, account2, XRD)? == dec!(800),
+        "Deposit of the second bidder was not refunded"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn can_cancel_auction_and_burn_nft() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+
+    let nft_manager = ResourceManager(get_state_resource_address(&mut env, component)?);
+    let nft_count = nft_manager
+        .total_supply(&mut env)
+        .expect("Could not get supply!")
+        .expect("Could not get supply!");
+
+    // Act
+    component.cancel_current_auction(1, NftPlacement::Burn, &mut env)?;
+
+    // Assert
+    let new_nft_count = nft_manager
+        .total_supply(&mut env)
+        .expect("Could not get supply!")
+        .expect("Could not get supply!");
+    assert!(
+        nft_count - new_nft_count == dec!(1),
+        "NFT was not burned"
+    );
+
+    let available_nfts = get_state_available_nfts_list(&mut env, component)?;
+    assert!(
+        !available_nfts.contains(&NonFungibleLocalId::integer(1)),
+        "Burned NFT is still in the queue"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_cancel_auction_without_owner() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, _owner_resource) = create_prepared_test_environment()?;
+
+    component.start_new_auction(&mut env)?;
+
+    // Act
+    let result = component.cancel_current_auction(1, NftPlacement::Back, &mut env);
+
+    // Assert
+    assert!(
+        result.is_err(),
+        "Auction was cancelled without an owner badge!"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_bid_while_paused() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    component.start_new_auction(&mut env)?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.pause(&mut env)?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    // Act
+    let result = component.bid(1, xrd_bucket.into(), account, &mut env);
+
+    // Assert
+    assert!(result.is_err(), "Bid was placed while paused!");
+
+    Ok(())
+}
+
+#[test]
+fn cannot_increase_bid_while_paused() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+    let increase_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(500), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.pause(&mut env)?;
+
+    // Act
+    let result = component.increase_bid(1, increase_bucket.into(), account, &mut env);
+
+    // Assert
+    assert!(result.is_err(), "Bid was increased while paused!");
+
+    Ok(())
+}
+
+#[test]
+fn cannot_buy_now_while_paused() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.delete_nft(NonFungibleLocalId::integer(1), &mut env)?;
+    component.delete_nft(NonFungibleLocalId::integer(2), &mut env)?;
+    mint_nfts_with_buy_now_price(&mut env, component, dec!(2000))?;
+
+    component.start_new_auction(&mut env)?;
+    component.pause(&mut env)?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(2000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    // Act
+    let result = component.buy_now(1, xrd_bucket.into(), account, &mut env);
+
+    // Assert
+    assert!(result.is_err(), "NFT was bought while paused!");
+
+    Ok(())
+}
+
+#[test]
+fn cannot_commit_bid_while_paused() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.set_nft_auction_type(
+        NonFungibleLocalId::integer(1),
+        AuctionType::SealedBid(SealedBidAuction {
+            pricing: SealedBidPricing::FirstPrice,
+            reveal_duration_minutes: 60,
+        }),
+        &mut env,
+    )?;
+
+    component.start_new_auction(&mut env)?;
+    component.pause(&mut env)?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    // Act
+    let result = component.commit_bid(
+        1,
+        SealedBid::commitment(dec!(1000), "salt"),
+        xrd_bucket.into(),
+        account,
+        &mut env,
+    );
+
+    // Assert
+    assert!(result.is_err(), "Sealed bid was committed while paused!");
+
+    Ok(())
+}
+
+#[test]
+fn cannot_settle_auction_while_paused() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(current_auction.end_timestamp);
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.pause(&mut env)?;
+
+    // Act
+    let result = component.settle_auction(1, account, &mut env);
+
+    // Assert
+    assert!(result.is_err(), "Auction was settled while paused!");
+
+    Ok(())
+}
+
+#[test]
+fn cannot_execute_swap_while_paused() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.update_deferred_swaps(
+        Some(DeferredSwaps {
+            bounty_percentage: dec!(0.01),
+            max_swap_amount: None,
+        }),
+        &mut env,
+    )?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(current_auction.end_timestamp);
+
+    component.settle_auction(1, account, &mut env)?;
+    component.pause(&mut env)?;
+
+    // Act
+    let result = component.execute_swap(dec!(500), &mut env);
+
+    // Assert
+    assert!(result.is_err(), "Swap was executed while paused!");
+    assert!(
+        component.get_treasury_amount(&mut env)? == dec!(950),
+        "XRD was taken from the treasury while paused"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn unpause_extends_active_auctions() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, owner_resource) = create_prepared_test_environment()?;
+
+    component.start_new_auction(&mut env)?;
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+
+    let owner_proof = owner_resource.create_proof_of_all(&mut env)?;
+    LocalAuthZone::push(owner_proof, &mut env)?;
+    component.pause(&mut env)?;
+
+    env.set_current_time(
+        current_auction
+            .start_timestamp
+            .add_minutes(30)
+            .expect("Could not add time"),
+    );
+
+    // Act
+    component.unpause(&mut env)?;
+
+    // Assert
+    let unpaused_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    assert!(
+        unpaused_auction.end_timestamp
+            == current_auction
+                .end_timestamp
+                .add_minutes(30)
+                .expect("Could not add time"),
+        "Auction was not extended by the paused duration"
+    );
+    assert!(
+        unpaused_auction.start_timestamp
+            == current_auction
+                .start_timestamp
+                .add_minutes(30)
+                .expect("Could not add time"),
+        "Auction start was not shifted by the paused duration"
+    );
+    assert!(
+        component.get_paused_at(&mut env)?.is_none(),
+        "Component is still paused"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_pause_without_pauser() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, _owner_resource) = create_prepared_test_environment()?;
+
+    // Act
+    let result = component.pause(&mut env);
+
+    // Assert
+    assert!(
+        result.is_err(),
+        "Component was paused without a pauser or owner badge!"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn settled_auction_records_lifecycle() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, _owner_resource) = create_prepared_test_environment()?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    assert!(
+        current_auction.status == AuctionStatus::Live,
+        "Auction is not live"
+    );
+
+    env.set_current_time(current_auction.end_timestamp);
+    let ended_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    assert!(
+        ended_auction.status == AuctionStatus::Ended,
+        "Auction has not ended"
+    );
+
+    // Act
+    let (reward, _nft) = component.settle_auction(1, account, &mut env)?;
+
+    // Assert
+    let completed_auction = component
+        .get_completed_auction(1, &mut env)?
+        .expect("No completed auction");
+    assert!(
+        completed_auction.status == AuctionStatus::Settled,
+        "Auction was not recorded as settled"
+    );
+    assert!(
+        completed_auction.settled_timestamp == Some(current_auction.end_timestamp),
+        "Settled timestamp was not recorded"
+    );
+    assert!(
+        completed_auction
+            .settler
+            .map(|settler| *settler.address().as_node_id())
+            == Some(*account.as_node_id()),
+        "Settler was not recorded"
+    );
+    assert!(
+        completed_auction.settler_reward == reward.amount(&mut env)?,
+        "Settler reward was not recorded"
+    );
+    assert!(
+        completed_auction.early_received > dec!(0),
+        "Received EARLY was not recorded"
+    );
+    assert!(
+        completed_auction.final_price == Some(dec!(1000)),
+        "Final price was not recorded"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn full_bid_history_is_kept() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, _owner_resource) = create_prepared_test_environment()?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
+
+    // Act
+    for _ in 0..11 {
+        let increase_bucket =
+            BucketFactory::create_fungible_bucket(XRD, dec!(500), Mock, &mut env)?;
+        component.increase_bid(1, increase_bucket.into(), account, &mut env)?;
+    }
+
+    // Assert
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    assert!(
+        current_auction.latest_bids.len() == 10,
+        "Latest bids were not limited to 10"
+    );
+
+    let bids = component.get_bids(1, 0, 100, &mut env)?;
+    assert!(bids.len() == 12, "Not all bids were kept");
+    assert!(bids[0].amount == dec!(1000), "First bid was not kept");
+
+    let page = component.get_bids(1, 10, 5, &mut env)?;
+    assert!(page.len() == 2, "Bids were not paginated");
+    assert!(
+        page[1].amount == dec!(6500),
+        "Last page does not end with the last bid"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn can_look_up_completed_auctions() -> Result<(), RuntimeError> {
+    // Arrange
+    let (mut env, mut component, _owner_resource) = create_prepared_test_environment()?;
+
+    let xrd_bucket = BucketFactory::create_fungible_bucket(XRD, dec!(1000), Mock, &mut env)?;
+
+    let account = create_account(&mut env, XRD)?;
+    let account_proof = ProofFactory::create_fungible_proof(XRD, dec!(1), Mock, &mut env)?;
+    LocalAuthZone::push(account_proof, &mut env)?;
+
+    component.start_new_auction(&mut env)?;
+    component.bid(1, xrd_bucket.into(), account, &mut env)?;
+
+    let current_auction = component
+        .get_current_auction(1, &mut env)
+        .expect("Couldn't get active auction")
+        .expect("No active auction");
+    env.set_current_time(current_auction.end_timestamp);
+
+    // Act
+    component.settle_auction(1, account, &mut env)?;
+
+    // Assert
+    assert!(
+        component.get_auction_count(&mut env)? == 2,
+        "Auction count does not include the next auction"
+    );
+    assert!(
+        component.get_completed_auction(2, &mut env)?.is_none(),
+        "Active auction was returned as completed"
+    );
+
+    let completed_auctions = component.get_completed_auctions(1, 10, &mut env)?;
+    assert!(
+        completed_auctions.len() == 1 && completed_auctions[0].id == 1,
+        "Completed auctions were not returned"
+    );
+
+    let nft_auctions = component.get_auctions_by_nft(NonFungibleLocalId::integer(1), 0, 10, &mut env)?;
+    assert!(
+        nft_auctions.len() == 1 && nft_auctions[0].id == 1,
+        "Auction was not found by NFT"
+    );
+
+    let account_address = ComponentAddress::try_from(*account.as_node_id())
+        .expect("Not a component address");
+    let winner_auctions = component.get_auctions_by_winner(account_address, 0, 10, &mut env)?;
+    assert!(
+        winner_auctions.len() == 1 && winner_auctions[0].id == 1,
+        "Auction was not found by winner"
+    );
+
+    Ok(())
+}